@@ -10,12 +10,12 @@ async fn main() -> anyhow::Result<()> {
 
 	let file_path = std::env::current_dir()?.join("src/lib.rs");
 	let buffer = std::fs::read(file_path)?;
-	let headers = oss_client.append_object("lib_twice.rs", buffer.clone(), 0).await?;
-	println!("append_object1 headers: {:?}", headers);
+	let output = oss_client.append_object("lib_twice.rs", buffer.clone(), 0, None).await?;
+	println!("append_object1 output: {:?}", output);
 
-	let next_position: usize = headers.get("x-oss-next-append-position").unwrap().to_str()?.parse().unwrap();
-	let headers = oss_client.append_object("lib_twice.rs", buffer, next_position).await?;
-	println!("append_object2 headers: {:?}", headers);
+	let next_position = output.next_append_position.unwrap_or_default() as usize;
+	let output = oss_client.append_object("lib_twice.rs", buffer, next_position, None).await?;
+	println!("append_object2 next position: {:?}", output.next_append_position);
 
 	Ok(())
 }
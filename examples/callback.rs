@@ -0,0 +1,23 @@
+// examples/common/mod.rs
+pub mod common;
+use ali_oss::{CallbackBodyType, CallbackOptions, Client, PutObjectOptions};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let oss_client = crate::Client::from_env()?;
+	let callback = CallbackOptions::new("https://example.com/oss/callback", r#"{"object":${object},"size":${size},"uid":${x:uid}}"#)
+		.body_type(CallbackBodyType::Json)
+		.var("uid", "10086");
+	let options = PutObjectOptions::new().callback(callback);
+	let output = oss_client.put_object("callback.txt", "hello", Some(&options)).await?;
+	println!("callback body: {:?}", output.callback_body);
+	println!("callback error: {:?}", output.callback_error);
+
+	Ok(())
+}
+
+// 回调服务器中校验请求:
+// let verifier = ali_oss::CallbackVerifier::new();
+// verifier.verify(path, query, &body, authorization_header, pub_key_url_header).await?;
@@ -0,0 +1,19 @@
+// examples/common/mod.rs
+pub mod common;
+use ali_oss::{Client, OssConfig};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let oss_client = Client::builder(OssConfig::from_env()?)
+		.connect_timeout(std::time::Duration::from_secs(5))
+		.read_timeout(std::time::Duration::from_secs(30))
+		.pool_max_idle_per_host(16)
+		.user_agent("ali-oss-example")
+		.build()?;
+	let buckets = oss_client.list_buckets().await?;
+	println!("buckets: {:?}", buckets);
+
+	Ok(())
+}
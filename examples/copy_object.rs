@@ -7,8 +7,8 @@ async fn main() -> anyhow::Result<()> {
 	common::load_env()?;
 
 	let oss_client = crate::Client::from_env()?;
-	let headers = oss_client.copy_object("/2.html", "/1.html").await?;
-	println!("copy_object headers: {:?}", headers);
+	let output = oss_client.copy_object("/2.html", "/1.html", None).await?;
+	println!("copy_object etag: {}, request id: {}", output.etag, output.request_id);
 
 	Ok(())
 }
@@ -0,0 +1,14 @@
+// examples/common/mod.rs
+pub mod common;
+use ali_oss::{ChainCredentialsProvider, Client, OssConfig};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let oss_client = Client::builder(OssConfig::from_env()?).credentials_provider(ChainCredentialsProvider::default()).build()?;
+	let buckets = oss_client.list_buckets().await?;
+	println!("buckets: {:?}", buckets);
+
+	Ok(())
+}
@@ -0,0 +1,19 @@
+// examples/common/mod.rs
+pub mod common;
+
+use ali_oss::{Client, DownloadFileOptions};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let oss_client = crate::Client::from_env()?;
+	let local_file = std::env::temp_dir().join("ali-oss-download-file.bin");
+
+	// 中断后使用相同参数再次调用会从断点继续下载
+	let options = DownloadFileOptions::new().part_size(1024 * 1024).parallel(3);
+	let meta = oss_client.download_file("upload_file.bin", &local_file, options).await?;
+	println!("meta: {:?}", meta);
+
+	Ok(())
+}
@@ -0,0 +1,15 @@
+// examples/common/mod.rs
+pub mod common;
+use ali_oss::{Client, EcsRamRoleCredentialsProvider, OssConfig};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let role_name = std::env::var("ALIBABA_CLOUD_ECS_METADATA").ok();
+	let oss_client = Client::builder(OssConfig::from_env()?).credentials_provider(EcsRamRoleCredentialsProvider::new(role_name)).build()?;
+	let buckets = oss_client.list_buckets().await?;
+	println!("buckets: {:?}", buckets);
+
+	Ok(())
+}
@@ -1,14 +1,32 @@
 // examples/common/mod.rs
 pub mod common;
-use ali_oss::Client;
+use ali_oss::{Client, ConditionalOptions, OssError, OssErrorCode};
+use futures::TryStreamExt;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
 	common::load_env()?;
 
 	let oss_client = crate::Client::from_env()?;
-	let (bytes, headers) = oss_client.get_object("1.html").await?;
+	let (bytes, headers) = oss_client.get_object("1.html", None).await?;
 	println!("bytes: {:?}, headers: {:?}", bytes, headers);
 
+	// 对象未修改时返回 NotModified
+	let etag = headers.get("etag").and_then(|etag| etag.to_str().ok()).unwrap_or_default();
+	match oss_client.get_object("1.html", Some(&ConditionalOptions::new().if_none_match(etag))).await {
+		Ok((bytes, _headers)) => println!("modified: {:?}", bytes),
+		Err(e) => match e.downcast_ref::<OssError>() {
+			Some(oss_error) if oss_error.code == OssErrorCode::NotModified => println!("not modified"),
+			_ => return Err(e),
+		},
+	}
+
+	let (stream, headers) = oss_client.get_object_stream("1.html", None).await?;
+	let chunks: Vec<bytes::Bytes> = stream.try_collect().await?;
+	println!("chunks: {}, headers: {:?}", chunks.len(), headers);
+
+	let (bytes, content_range, _headers) = oss_client.get_object_range("1.html", 0..10, None).await?;
+	println!("bytes: {:?}, content_range: {:?}", bytes, content_range);
+
 	Ok(())
 }
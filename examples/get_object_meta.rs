@@ -7,8 +7,8 @@ async fn main() -> anyhow::Result<()> {
 	common::load_env()?;
 
 	let oss_client = crate::Client::from_env()?;
-	let headers = oss_client.get_object_meta("1.html").await?;
-	println!("get_object_meta headers: {:?}", headers);
+	let meta = oss_client.get_object_meta("1.html", None).await?;
+	println!("get_object_meta meta: {:?}", meta);
 
 	Ok(())
 }
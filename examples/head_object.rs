@@ -7,8 +7,9 @@ async fn main() -> anyhow::Result<()> {
 	common::load_env()?;
 
 	let oss_client = crate::Client::from_env()?;
-	let headers = oss_client.head_object("lib.rs").await?;
-	println!("head_object headers: {:?}", headers);
+	let meta = oss_client.head_object("lib.rs", None).await?;
+	println!("head_object meta: {:?}", meta);
+	println!("size: {}, etag: {}, object type: {:?}, user metadata: {:?}", meta.content_length, meta.etag, meta.object_type, meta.metadata);
 
 	Ok(())
 }
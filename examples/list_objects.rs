@@ -1,7 +1,8 @@
 // examples/common/mod.rs
 pub mod common;
 
-use ali_oss::Client;
+use ali_oss::{Client, ListObjectsOptions};
+use futures::TryStreamExt;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -17,5 +18,13 @@ async fn main() -> anyhow::Result<()> {
 	let files = oss_client.list_files(None).await?;
 	println!("files: {:?}", files);
 
+	let page = oss_client.list_objects_v2(&ListObjectsOptions::new().max_keys(10).fetch_owner(true).encoding_url(true)).await?;
+	println!("page: {:?}", page);
+
+	let mut stream = std::pin::pin!(oss_client.list_objects_stream(ListObjectsOptions::new().delimiter("/")));
+	while let Some(item) = stream.try_next().await? {
+		println!("item: {:?}", item);
+	}
+
 	Ok(())
 }
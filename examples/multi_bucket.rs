@@ -0,0 +1,18 @@
+// examples/common/mod.rs
+pub mod common;
+use ali_oss::Client;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let oss_client = crate::Client::from_env()?;
+	let buckets = oss_client.list_buckets().await?;
+	for bucket in buckets {
+		let bucket_client = oss_client.bucket(bucket.name.as_str(), bucket.location.as_str());
+		let files = bucket_client.list_files(None).await?;
+		println!("bucket: {}, files: {:?}", bucket.name, files);
+	}
+
+	Ok(())
+}
@@ -0,0 +1,33 @@
+// examples/common/mod.rs
+pub mod common;
+
+use ali_oss::{Client, ListMultipartUploadsOptions};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let oss_client = crate::Client::from_env()?;
+	let object_name = "multipart.txt";
+	let upload = oss_client.initiate_multipart_upload(object_name).await?;
+	println!("upload: {:?}", upload);
+
+	// 除最后一个分片外, 每个分片不小于 100KB
+	let mut parts = Vec::new();
+	parts.push(oss_client.upload_part(object_name, &upload.upload_id, 1, vec![b'a'; 100 * 1024]).await?);
+	parts.push(oss_client.upload_part(object_name, &upload.upload_id, 2, "end").await?);
+
+	let list_parts = oss_client.list_parts(object_name, &upload.upload_id, None, None).await?;
+	println!("list_parts: {:?}", list_parts);
+
+	let uploads = oss_client.list_multipart_uploads(&ListMultipartUploadsOptions::new()).await?;
+	println!("uploads: {:?}", uploads);
+
+	let result = oss_client.complete_multipart_upload(object_name, &upload.upload_id, Some(&parts), None).await?;
+	println!("result: {:?}", result);
+
+	let aborted = oss_client.initiate_multipart_upload(object_name).await?;
+	oss_client.abort_multipart_upload(object_name, &aborted.upload_id).await?;
+
+	Ok(())
+}
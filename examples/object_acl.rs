@@ -0,0 +1,18 @@
+// examples/common/mod.rs
+pub mod common;
+use ali_oss::{Acl, Client};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let oss_client = crate::Client::from_env()?;
+	oss_client.put_object_acl("1.html", Acl::PublicRead).await?;
+	let policy = oss_client.get_object_acl("1.html").await?;
+	println!("owner: {:?}, grant: {}", policy.owner, policy.grant);
+
+	// 恢复为继承 bucket 的权限
+	oss_client.put_object_acl("1.html", Acl::Default).await?;
+
+	Ok(())
+}
@@ -0,0 +1,27 @@
+// examples/common/mod.rs
+pub mod common;
+use ali_oss::{Client, PostPolicy};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let oss_client = crate::Client::from_env()?;
+	let policy = PostPolicy::new()
+		.expires(std::time::Duration::from_secs(600))
+		.key_prefix("uploads/")
+		.content_length_range(1, 10 * 1024 * 1024)
+		.content_type_prefix("image/")
+		.success_action_status(201);
+	let form = oss_client.post_object_form(&policy).await?;
+
+	// 前端以 multipart/form-data 提交, file 必须是最后一个字段
+	let mut command = format!("curl -X POST {}", form.url);
+	for (k, v) in &form.fields {
+		command.push_str(&format!(" -F '{}={}'", k, v));
+	}
+	command.push_str(" -F 'key=uploads/logo.png' -F 'Content-Type=image/png' -F 'file=@logo.png'");
+	println!("{}", command);
+
+	Ok(())
+}
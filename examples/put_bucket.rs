@@ -1,14 +1,19 @@
 // examples/common/mod.rs
 pub mod common;
-use ali_oss::Client;
+use ali_oss::{Acl, Client, DataRedundancyType, PutBucketOptions, StorageClass};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
 	common::load_env()?;
 
 	let oss_client = crate::Client::from_env()?;
-	let bucket = oss_client.put_bucket().await?;
+	let options = PutBucketOptions::new().acl(Acl::Private).storage_class(StorageClass::Standard).data_redundancy_type(DataRedundancyType::LRS);
+	let bucket = oss_client.put_bucket(Some(&options)).await?;
 	println!("bucket: {:?}", bucket);
 
+	oss_client.put_bucket_acl(Acl::PublicRead).await?;
+	let policy = oss_client.get_bucket_acl().await?;
+	println!("bucket acl: {}", policy.grant);
+
 	Ok(())
 }
@@ -1,6 +1,6 @@
 // examples/common/mod.rs
 pub mod common;
-use ali_oss::Client;
+use ali_oss::{Acl, Client, PutObjectOptions, StorageClass};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -9,8 +9,19 @@ async fn main() -> anyhow::Result<()> {
 	let oss_client = crate::Client::from_env()?;
 	let file_path = std::env::current_dir()?.join("src/lib.rs");
 	let buffer = std::fs::read(file_path)?;
-	let headers = oss_client.put_object("/lib.rs", buffer).await?;
-	println!("put_object headers: {:?}", headers);
+	let output = oss_client.put_object("/lib.rs", buffer.clone(), None).await?;
+	println!("put_object output: {:?}", output);
+
+	let options = PutObjectOptions::new()
+		.content_type("text/plain; charset=utf-8")
+		.cache_control("max-age=3600")
+		.meta("author", "ali-oss")
+		.acl(Acl::Private)
+		.storage_class(StorageClass::Standard)
+		.tag("project", "demo")
+		.forbid_overwrite(true);
+	let output = oss_client.put_object("/lib_with_options.rs", buffer, Some(&options)).await?;
+	println!("put_object with options request id: {}", output.request_id);
 
 	Ok(())
 }
@@ -8,8 +8,8 @@ async fn main() -> anyhow::Result<()> {
 
 	let oss_client = crate::Client::from_env()?;
 	let response = reqwest::get("https://debug.zezeping.com/1.html").await?;
-	let headers = oss_client.put_object_stream("/1.html", response.bytes_stream()).await?;
-	println!("put_object headers: {:?}", headers);
+	let output = oss_client.put_object_stream("/1.html", response.bytes_stream(), None).await?;
+	println!("put_object output: {:?}", output);
 
 	Ok(())
 }
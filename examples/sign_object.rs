@@ -1,6 +1,6 @@
 // examples/common/mod.rs
 pub mod common;
-use ali_oss::Client;
+use ali_oss::{Client, PresignOptions};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -10,5 +10,15 @@ async fn main() -> anyhow::Result<()> {
 	let url = oss_client.sign_object("1.html", std::time::Duration::from_secs(300)).await?;
 	println!("sign_object url: {:?}", url);
 
+	// 浏览器直接下载为附件
+	let options = PresignOptions::new().response_content_disposition("attachment; filename=\"1.html\"");
+	let url = oss_client.presign_object("1.html", std::time::Duration::from_secs(300), &options).await?;
+	println!("download url: {:?}", url);
+
+	// curl -X PUT -H "Content-Type: text/html" -H "x-oss-object-acl: private" --data-binary @1.html "{url}"
+	let options = PresignOptions::new().method(reqwest::Method::PUT).content_type("text/html").header("x-oss-object-acl", "private");
+	let url = oss_client.presign_object("1.html", std::time::Duration::from_secs(300), &options).await?;
+	println!("upload url: {:?}", url);
+
 	Ok(())
 }
@@ -0,0 +1,20 @@
+// examples/common/mod.rs
+pub mod common;
+
+use ali_oss::{Client, UploadFileOptions};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	common::load_env()?;
+
+	let oss_client = crate::Client::from_env()?;
+	let local_file = std::env::temp_dir().join("ali-oss-upload-file.bin");
+	tokio::fs::write(&local_file, vec![b'a'; 3 * 1024 * 1024 + 1]).await?;
+
+	// 中断后使用相同参数再次调用会从断点继续上传
+	let options = UploadFileOptions::new().part_size(1024 * 1024).parallel(3);
+	let result = oss_client.upload_file(&local_file, "upload_file.bin", options).await?;
+	println!("result: {:?}", result);
+
+	Ok(())
+}
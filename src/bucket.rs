@@ -1,10 +1,9 @@
-use std::sync::Mutex;
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Bucket {
 	pub name: String,
 	pub location: crate::types::BucketLocation,
 	pub comment: String,
-	pub creation_date: Mutex<Option<chrono::DateTime<chrono::Utc>>>,
+	pub creation_date: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl Bucket {
@@ -13,7 +12,7 @@ impl Bucket {
 			name: name.to_string(),
 			location: crate::types::BucketLocation::new(location),
 			comment: comment.to_string(),
-			creation_date: Mutex::new(creation_date),
+			creation_date,
 		}
 	}
 	pub fn new_from_xml_node(node: roxmltree::Node) -> anyhow::Result<Self> {
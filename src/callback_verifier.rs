@@ -0,0 +1,134 @@
+use base64::prelude::*;
+use md5_digest::Md5;
+use rsa::pkcs1::DecodeRsaPublicKey;
+use rsa::pkcs1v15::{Signature, VerifyingKey};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::signature::Verifier;
+use rsa::RsaPublicKey;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+// OSS 回调公钥所在的域名
+const PUBLIC_KEY_HOST: &str = "gosspublic.alicdn.com";
+// 缓存的公钥数量上限
+const MAX_CACHED_PUBLIC_KEYS: usize = 16;
+// 下载公钥的超时时间
+const PUBLIC_KEY_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// # 校验 OSS 发送的上传回调请求
+/// 在回调服务器中使用: 签名为 `url_decode(path) + [?query] + "\n" + body` 的 MD5 RSA 签名,
+/// 公钥的地址必须在 OSS 的公钥域名下, 并始终通过 https 下载, 按路径缓存
+/// ```no_run
+/// # async fn run(path: &str, query: Option<&str>, body: &[u8], authorization: &str, pub_key_url: &str) -> anyhow::Result<()> {
+/// let verifier = ali_oss::CallbackVerifier::new();
+/// // authorization 与 pub_key_url 分别为请求头 authorization 与 x-oss-pub-key-url 的值
+/// verifier.verify(path, query, body, authorization, pub_key_url).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CallbackVerifier {
+	http_client: reqwest::Client,
+	public_keys: Arc<RwLock<HashMap<String, RsaPublicKey>>>,
+}
+
+impl Default for CallbackVerifier {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl CallbackVerifier {
+	pub fn new() -> Self {
+		Self::new_with_client(reqwest::Client::new())
+	}
+	/// 下载公钥时总会设置请求超时
+	pub fn new_with_client(http_client: reqwest::Client) -> Self {
+		Self {
+			http_client,
+			public_keys: Arc::new(RwLock::new(HashMap::new())),
+		}
+	}
+
+	/// # 校验回调请求
+	/// path 为请求路径(可以是 URL 编码的), query 为原始的查询字符串(不含 `?`), pub_key_url 为 base64 编码的 x-oss-pub-key-url
+	pub async fn verify(&self, path: &str, query: Option<&str>, body: &[u8], authorization: &str, pub_key_url: &str) -> anyhow::Result<()> {
+		let pub_key_url = String::from_utf8(BASE64_STANDARD.decode(pub_key_url.trim())?)?;
+		let url = reqwest::Url::parse(&pub_key_url)?;
+		// OSS 可能给出 http 地址, 只取其路径, 公钥总是从固定域名通过 https 下载
+		if !matches!(url.scheme(), "http" | "https") || url.host_str() != Some(PUBLIC_KEY_HOST) || url.port().is_some() || url.query().is_some() {
+			anyhow::bail!("untrusted public key url {}", pub_key_url);
+		}
+		let key_path = url.path().to_string();
+		let cached = self.public_keys.read().unwrap().get(&key_path).cloned();
+		let public_key = match cached {
+			Some(public_key) => public_key,
+			None => {
+				let key_url = format!("https://{}{}", PUBLIC_KEY_HOST, key_path);
+				let pem = self.http_client.get(key_url).timeout(PUBLIC_KEY_TIMEOUT).send().await?.error_for_status()?.text().await?;
+				let public_key = parse_public_key(&pem)?;
+				let mut public_keys = self.public_keys.write().unwrap();
+				if public_keys.len() >= MAX_CACHED_PUBLIC_KEYS {
+					public_keys.clear();
+				}
+				public_keys.insert(key_path, public_key.clone());
+				public_key
+			}
+		};
+		verify_signature(public_key, path, query, body, authorization)
+	}
+
+	/// # 使用已知的公钥校验回调请求
+	/// 公钥可以是 `PUBLIC KEY`(SubjectPublicKeyInfo) 或 `RSA PUBLIC KEY`(PKCS#1) 格式
+	/// ```
+	/// # use ali_oss::CallbackVerifier;
+	/// let spki_public_key = "-----BEGIN PUBLIC KEY-----
+	/// MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQKBgQDA5EyZv4VOZY69Jc3pUs+jQXVT
+	/// ymcDFYVq8VwFCKPDEE1Y/v2k4Qhiom/jOv/ojVwmM443G1gwSZPkZJ3TgOHN2z8T
+	/// QOR+OMv0A4nd/eIYXYvJ/RGOBJ/ErRCuWnoRmfvUYJpedhJv51pkpDIrzbiKeIz7
+	/// 7+K2z2ihwhgRfr6r9wIDAQAB
+	/// -----END PUBLIC KEY-----";
+	/// let pkcs1_public_key = "-----BEGIN RSA PUBLIC KEY-----
+	/// MIGJAoGBAMDkTJm/hU5ljr0lzelSz6NBdVPKZwMVhWrxXAUIo8MQTVj+/aThCGKi
+	/// b+M6/+iNXCYzjjcbWDBJk+RkndOA4c3bPxNA5H44y/QDid394hhdi8n9EY4En8St
+	/// EK5aehGZ+9Rgml52Em/nWmSkMivNuIp4jPvv4rbPaKHCGBF+vqv3AgMBAAE=
+	/// -----END RSA PUBLIC KEY-----";
+	/// let authorization = "iEwDq/hBsv0WTlSGXOglpSfFCZ3iTejM0vcrLrZ5JpeQ7wEUcuAEN8Z68jo0+GfYU/rIngfm3bnI1r1LppdEWBmZF+AIZWWsZYvfTg0E+5L3WxlISSfkiArBrIdhbie+KQzXbb+XUzpcZCCswb2Tlj04LBgRvJhARGjdGwJ1v8U=";
+	/// let body = b"bucket=examplebucket&object=exampleobject.txt&etag=D8E8FCA2DC0F896FD7CB4CB0031BA249&size=5&mimeType=text%2Fplain";
+	/// // 签名正确
+	/// assert!(CallbackVerifier::verify_with_public_key(spki_public_key, "/oss/callback", Some("from=oss"), body, authorization).is_ok());
+	/// assert!(CallbackVerifier::verify_with_public_key(pkcs1_public_key, "/oss/callback", Some("from=oss"), body, authorization).is_ok());
+	/// // 路径按解码后的值签名
+	/// assert!(CallbackVerifier::verify_with_public_key(spki_public_key, "/oss/%63allback", Some("from=oss"), body, authorization).is_ok());
+	/// // 请求体被修改
+	/// let tampered_body = b"bucket=examplebucket&object=exampleobject.txt&etag=D8E8FCA2DC0F896FD7CB4CB0031BA249&size=6&mimeType=text%2Fplain";
+	/// assert!(CallbackVerifier::verify_with_public_key(spki_public_key, "/oss/callback", Some("from=oss"), tampered_body, authorization).is_err());
+	/// // 查询字符串被修改或去掉
+	/// assert!(CallbackVerifier::verify_with_public_key(pkcs1_public_key, "/oss/callback", Some("from=evil"), body, authorization).is_err());
+	/// assert!(CallbackVerifier::verify_with_public_key(pkcs1_public_key, "/oss/callback", None, body, authorization).is_err());
+	/// ```
+	pub fn verify_with_public_key(public_key: &str, path: &str, query: Option<&str>, body: &[u8], authorization: &str) -> anyhow::Result<()> {
+		verify_signature(parse_public_key(public_key)?, path, query, body, authorization)
+	}
+}
+
+fn parse_public_key(pem: &str) -> anyhow::Result<RsaPublicKey> {
+	let pem = pem.trim();
+	if pem.starts_with("-----BEGIN RSA PUBLIC KEY-----") {
+		Ok(RsaPublicKey::from_pkcs1_pem(pem)?)
+	} else {
+		Ok(RsaPublicKey::from_public_key_pem(pem)?)
+	}
+}
+
+fn verify_signature(public_key: RsaPublicKey, path: &str, query: Option<&str>, body: &[u8], authorization: &str) -> anyhow::Result<()> {
+	let signature = Signature::try_from(BASE64_STANDARD.decode(authorization.trim())?.as_slice())?;
+	let mut message = percent_encoding::percent_decode_str(path).collect::<Vec<u8>>();
+	if let Some(query) = query.filter(|query| !query.is_empty()) {
+		message.push(b'?');
+		message.extend_from_slice(query.as_bytes());
+	}
+	message.push(b'\n');
+	message.extend_from_slice(body);
+	VerifyingKey::<Md5>::new(public_key).verify(&message, &signature).map_err(|_| anyhow::anyhow!("callback signature mismatch"))
+}
@@ -1,43 +1,120 @@
-use crate::types::OssConfig;
-use crate::SignatureAble;
-use base64::prelude::*;
+use crate::types::{OssConfig, OssError, OssErrorCode};
 
-#[derive(Debug)]
+/// # OSS 客户端
+/// 凭证, 连接池与重试策略在 clone 及 `bucket()` 得到的客户端之间共享
+#[derive(Debug, Clone)]
 pub struct Client {
 	oss_config: OssConfig,
-	bucket: crate::Bucket,
+	http_client: reqwest::Client,
+	retry_policy: std::sync::Arc<dyn crate::RetryPolicy>,
+	credentials_provider: std::sync::Arc<dyn crate::CredentialsProvider>,
 }
 
 impl Client {
 	pub fn from_env() -> anyhow::Result<Self> {
 		let oss_config = OssConfig::from_env()?;
-		let bucket = crate::Bucket::new(oss_config.bucket_name.as_str(), oss_config.bucket_location.as_str(), "", None);
-		Ok(Self { oss_config, bucket })
+		crate::ClientBuilder::new(oss_config).build()
 	}
 	pub fn new<T: ToString>(access_key_id: T, access_key_secret: T, bucket_name: T, bucket_location: T, path: T, is_internal: bool) -> Self {
 		let oss_config = OssConfig::new(
 			access_key_id.to_string(),
 			access_key_secret.to_string(),
-			bucket_name.to_string().clone(),
-			bucket_location.to_string().clone(),
-			path.to_string().clone(),
+			bucket_name.to_string(),
+			bucket_location.to_string(),
+			path.to_string(),
 			is_internal,
 		);
-		let bucket = crate::Bucket::new(bucket_name, bucket_location, "", None);
-		Self { oss_config, bucket }
+		let credentials_provider = std::sync::Arc::new(crate::StaticCredentialsProvider::new_from_credentials(oss_config.get_credentials()));
+		Self::new_with_parts(oss_config, reqwest::Client::new(), std::sync::Arc::new(crate::ExponentialBackoff::default()), credentials_provider)
+	}
+	pub fn builder(oss_config: OssConfig) -> crate::ClientBuilder {
+		crate::ClientBuilder::new(oss_config)
+	}
+	pub(crate) fn new_with_parts(
+		oss_config: OssConfig,
+		http_client: reqwest::Client,
+		retry_policy: std::sync::Arc<dyn crate::RetryPolicy>,
+		credentials_provider: std::sync::Arc<dyn crate::CredentialsProvider>,
+	) -> Self {
+		Self {
+			oss_config,
+			http_client,
+			retry_policy,
+			credentials_provider,
+		}
+	}
+
+	/// # 操作另一个 bucket 的客户端
+	/// 与当前客户端共享凭证与连接池, path 前缀会被清空; CNAME 域名不会沿用, 包含地域的自定义域名会切换到新的地域
+	/// ```
+	/// # use ali_oss::{AddressingStyle, Client, Endpoint, OssConfig};
+	/// fn assert_send_sync<T: Send + Sync + Clone>(_: &T) {}
+	/// let client = Client::new("id", "secret", "bucket-a", "oss-cn-hangzhou", "", false);
+	/// let other = client.bucket("bucket-b", "oss-cn-shanghai");
+	/// assert_send_sync(&other);
+	/// assert_eq!(other.oss_config().bucket_name, "bucket-b");
+	/// assert_eq!(client.oss_config().bucket_name, "bucket-a");
+	/// assert_eq!(other.oss_config().get_bucket_url().unwrap().as_str(), "https://bucket-b.oss-cn-shanghai.aliyuncs.com/");
+	///
+	/// let oss_config = OssConfig::new("id".to_string(), "secret".to_string(), "bucket-a".to_string(), "oss-cn-hangzhou".to_string(), "".to_string(), false);
+	/// let cname = Endpoint::parse("https://static.example.com").unwrap().addressing_style(AddressingStyle::Cname);
+	/// let client = Client::builder(oss_config.clone()).endpoint(cname).build().unwrap();
+	/// assert_eq!(client.bucket("bucket-b", "oss-cn-shanghai").oss_config().get_bucket_url().unwrap().as_str(), "https://bucket-b.oss-cn-shanghai.aliyuncs.com/");
+	///
+	/// let internal = Endpoint::parse("http://oss-cn-hangzhou-internal.aliyuncs.com").unwrap();
+	/// let client = Client::builder(oss_config).endpoint(internal).build().unwrap();
+	/// assert_eq!(client.bucket("bucket-b", "oss-cn-shanghai").oss_config().get_bucket_url().unwrap().as_str(), "http://bucket-b.oss-cn-shanghai-internal.aliyuncs.com/");
+	/// ```
+	pub fn bucket<T: ToString>(&self, bucket_name: T, bucket_location: T) -> Self {
+		let mut client = self.clone();
+		let bucket_location = crate::types::BucketLocation::new(bucket_location);
+		client.oss_config.endpoint = self.oss_config.endpoint.as_ref().and_then(|endpoint| endpoint.for_location(self.oss_config.bucket_location.as_str(), bucket_location.as_str()));
+		client.oss_config.bucket_name = bucket_name.to_string();
+		client.oss_config.bucket_location = bucket_location;
+		client.oss_config.path = "".to_string();
+		client
+	}
+	pub fn oss_config(&self) -> &OssConfig {
+		&self.oss_config
+	}
+
+	/// # 签名并发送请求
+	/// 失败时按 `RetryPolicy` 重试, 每次重试都会重新签名; 请求体无法复制(流式上传)时只发送一次,
+	/// 非幂等的请求只在 `RetryPolicy::is_retryable_method` 允许或连接未建立时重试
+	pub(crate) async fn send(&self, mut request: reqwest::Request) -> anyhow::Result<reqwest::Response> {
+		let max_attempts = self.retry_policy.max_attempts().max(1);
+		let mut attempt = 0;
+		loop {
+			attempt += 1;
+			let next_request = if attempt < max_attempts { request.try_clone() } else { None };
+			let is_retryable_method = self.retry_policy.is_retryable_method(request.method());
+			let credentials = self.credentials_provider.get_credentials().await?;
+			self.oss_config.sign_header_request(&mut request, &credentials)?;
+			let error: anyhow::Error = match self.http_client.execute(request).await {
+				Ok(response) if response.status().is_success() => return Ok(response),
+				Ok(response) => match OssError::from_response(response).await {
+					Ok(oss_error) => oss_error.into(),
+					Err(e) => e,
+				},
+				Err(e) => e.into(),
+			};
+			match next_request {
+				Some(next_request) if self.retry_policy.is_retryable(&error) && (is_retryable_method || crate::types::retry_policy::is_connect_error(&error)) => {
+					tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+					request = next_request;
+				}
+				_ => return Err(error),
+			}
+		}
 	}
 }
 
 impl Client {
 	// https://www.alibabacloud.com/help/zh/oss/developer-reference/listbuckets
 	pub async fn list_buckets(&self) -> anyhow::Result<Vec<crate::Bucket>> {
-		let mut request = self.oss_config.get_endpoint_request(reqwest::Method::GET)?;
-		self.oss_config.sign_header_request(&mut request)?;
+		let request = self.oss_config.get_endpoint_request(reqwest::Method::GET)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
+		let response = self.send(request).await?;
 		let xml_data = response.text().await?;
 		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
 		let mut buckets = Vec::new();
@@ -49,14 +126,18 @@ impl Client {
 	}
 
 	// https://help.aliyun.com/zh/oss/developer-reference/putbucket
-	pub async fn put_bucket(&self) -> anyhow::Result<crate::Bucket> {
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::PUT, None)?;
-		self.oss_config.sign_header_request(&mut request)?;
-
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
+	pub async fn put_bucket(&self, options: Option<&crate::PutBucketOptions>) -> anyhow::Result<crate::Bucket> {
+		let xml_body = options.and_then(|options| options.get_xml_body());
+		let has_body = xml_body.is_some();
+		let mut request = self.oss_config.get_bucket_request(reqwest::Method::PUT, xml_body.map(|xml_body| xml_body.into()))?;
+		if has_body {
+			request.headers_mut().insert("Content-Type", "application/xml".try_into()?);
+		}
+		if let Some(options) = options {
+			options.apply(request.headers_mut())?;
 		}
+
+		let response = self.send(request).await?;
 		let creation_date = {
 			let date = response.headers().get("date");
 			if let Some(date) = date {
@@ -65,8 +146,30 @@ impl Client {
 				None
 			}
 		};
-		*self.bucket.creation_date.lock().unwrap() = creation_date.clone();
-		Ok(crate::Bucket::new(self.bucket.name.as_str(), self.bucket.location.as_str(), "", creation_date))
+		Ok(crate::Bucket::new(self.oss_config.bucket_name.as_str(), self.oss_config.bucket_location.as_str(), "", creation_date))
+	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/putbucketacl
+	pub async fn put_bucket_acl(&self, acl: crate::Acl) -> anyhow::Result<()> {
+		static ACL: &str = "acl";
+		let mut request = self.oss_config.get_bucket_request(reqwest::Method::PUT, None)?;
+		request.url_mut().set_query(Some(ACL));
+		request.headers_mut().insert("x-oss-acl", acl.as_str().try_into()?);
+
+		self.send(request).await?;
+		Ok(())
+	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/getbucketacl
+	pub async fn get_bucket_acl(&self) -> anyhow::Result<crate::AccessControlPolicy> {
+		static ACL: &str = "acl";
+		let mut request = self.oss_config.get_bucket_request(reqwest::Method::GET, None)?;
+		request.url_mut().set_query(Some(ACL));
+
+		let response = self.send(request).await?;
+		let xml_data = response.text().await?;
+		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
+		crate::AccessControlPolicy::new_from_xml_node(doc.root_element())
 	}
 
 	// https://help.aliyun.com/zh/oss/developer-reference/getbucketinfo
@@ -74,18 +177,12 @@ impl Client {
 		static BUCKET_INFO: &str = "bucketInfo";
 		let mut request = self.oss_config.get_bucket_request(reqwest::Method::GET, None)?;
 		request.url_mut().set_query(Some(BUCKET_INFO));
-		self.oss_config.sign_header_request(&mut request)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
+		let response = self.send(request).await?;
 		let xml_data = response.text().await?;
 		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
 		if let Some(bucket_node) = doc.descendants().find(|n| n.has_tag_name("Bucket")) {
-			let bucket = crate::Bucket::new_from_xml_node(bucket_node)?;
-			*self.bucket.creation_date.lock().unwrap() = bucket.creation_date.lock().unwrap().clone();
-			return Ok(Some(bucket));
+			return Ok(Some(crate::Bucket::new_from_xml_node(bucket_node)?));
 		}
 		Ok(None)
 	}
@@ -95,12 +192,8 @@ impl Client {
 		static BUCKET_LOCATION: &str = "location";
 		let mut request = self.oss_config.get_bucket_request(reqwest::Method::GET, None)?;
 		request.url_mut().set_query(Some(BUCKET_LOCATION));
-		self.oss_config.sign_header_request(&mut request)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
+		let response = self.send(request).await?;
 		let xml_data = response.text().await?;
 		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
 		crate::types::BucketLocation::new_from_xml_node(doc.root())
@@ -111,12 +204,8 @@ impl Client {
 		static BUCKET_STAT: &str = "stat";
 		let mut request = self.oss_config.get_bucket_request(reqwest::Method::GET, None)?;
 		request.url_mut().set_query(Some(BUCKET_STAT));
-		self.oss_config.sign_header_request(&mut request)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
+		let response = self.send(request).await?;
 		let xml_data = response.text().await?;
 		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
 		crate::types::BucketStat::new_from_xml_node(doc.root())
@@ -124,45 +213,89 @@ impl Client {
 
 	// https://help.aliyun.com/zh/oss/developer-reference/deletebucket
 	pub async fn delete_bucket(&self) -> anyhow::Result<()> {
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::DELETE, None)?;
-		self.oss_config.sign_header_request(&mut request)?;
+		let request = self.oss_config.get_bucket_request(reqwest::Method::DELETE, None)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
-		*self.bucket.creation_date.lock().unwrap() = None;
+		self.send(request).await?;
 		Ok(())
 	}
 }
 
 impl Client {
 	// https://www.alibabacloud.com/help/zh/oss/developer-reference/listobjectsv2
-	pub async fn list_objects(&self, prefix: Option<&str>, delimiter: Option<&str>) -> anyhow::Result<(Vec<crate::Folder>, Vec<crate::File>)> {
+	/// # 列举单页对象
+	/// prefix 与 start_after 会拼接 OssConfig 中的 path; 结果未列举完时使用 next_continuation_token 继续请求
+	pub async fn list_objects_v2(&self, options: &crate::ListObjectsOptions) -> anyhow::Result<crate::ListObjectsResult> {
 		let mut request = self.oss_config.get_bucket_request(reqwest::Method::GET, None)?;
-		request.url_mut().query_pairs_mut().append_pair("list-type", "2");
-		if let Some(prefix) = prefix {
-			let prefix = self.oss_config.get_object_name(prefix);
-			request.url_mut().query_pairs_mut().append_pair("prefix", prefix.as_ref());
-		}
-		if let Some(delimiter) = delimiter {
-			request.url_mut().query_pairs_mut().append_pair("delimiter", delimiter);
+		{
+			let mut query = request.url_mut().query_pairs_mut();
+			query.append_pair("list-type", "2");
+			if let Some(prefix) = &options.prefix {
+				query.append_pair("prefix", self.oss_config.get_object_name(prefix).as_ref());
+			}
+			if let Some(delimiter) = &options.delimiter {
+				query.append_pair("delimiter", delimiter);
+			}
+			if let Some(max_keys) = options.max_keys {
+				query.append_pair("max-keys", &max_keys.to_string());
+			}
+			if let Some(continuation_token) = &options.continuation_token {
+				query.append_pair("continuation-token", continuation_token);
+			}
+			if let Some(start_after) = &options.start_after {
+				query.append_pair("start-after", self.oss_config.get_object_name(start_after).as_ref());
+			}
+			if options.fetch_owner {
+				query.append_pair("fetch-owner", "true");
+			}
+			if options.encoding_url {
+				query.append_pair("encoding-type", "url");
+			}
 		}
-		self.oss_config.sign_header_request(&mut request)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
+		let response = self.send(request).await?;
 		let xml_data = response.text().await?;
 		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
+		crate::ListObjectsResult::new_from_xml_node(doc.root_element())
+	}
+	/// # 以流的方式列举全部对象
+	/// 自动翻页, 每页的目录在前, 文件在后
+	pub fn list_objects_stream(&self, options: crate::ListObjectsOptions) -> impl futures::Stream<Item = anyhow::Result<crate::ListObjectsItem>> + Send + 'static {
+		use futures::TryStreamExt;
+		let client = self.clone();
+		futures::stream::try_unfold(Some(options), move |options| {
+			let client = client.clone();
+			async move {
+				let Some(options) = options else {
+					return anyhow::Ok(None);
+				};
+				let page = client.list_objects_v2(&options).await?;
+				let next_options = match (page.is_truncated, page.next_continuation_token) {
+					(true, Some(next_continuation_token)) => Some(options.continuation_token(next_continuation_token)),
+					_ => None,
+				};
+				let items = page.folders.into_iter().map(crate::ListObjectsItem::Folder).chain(page.files.into_iter().map(crate::ListObjectsItem::File));
+				Ok(Some((futures::stream::iter(items.map(Ok::<_, anyhow::Error>)), next_options)))
+			}
+		})
+		.try_flatten()
+	}
+	/// # 列举全部目录与文件
+	/// 会自动翻页直到列举完成
+	pub async fn list_objects(&self, prefix: Option<&str>, delimiter: Option<&str>) -> anyhow::Result<(Vec<crate::Folder>, Vec<crate::File>)> {
+		let mut options = crate::ListObjectsOptions::new().max_keys(1000);
+		options.prefix = prefix.map(|prefix| prefix.to_string());
+		options.delimiter = delimiter.map(|delimiter| delimiter.to_string());
+
 		let mut folders = Vec::new();
-		for folder_node in doc.descendants().filter(|n| n.has_tag_name("CommonPrefixes")) {
-			folders.push(crate::Folder::new_from_xml_node(folder_node)?);
-		}
 		let mut files = Vec::new();
-		for file_node in doc.descendants().filter(|n| n.has_tag_name("Contents")) {
-			files.push(crate::File::new_from_xml_node(file_node)?);
+		loop {
+			let page = self.list_objects_v2(&options).await?;
+			folders.extend(page.folders);
+			files.extend(page.files);
+			match (page.is_truncated, page.next_continuation_token) {
+				(true, Some(next_continuation_token)) => options.continuation_token = Some(next_continuation_token),
+				_ => break,
+			}
 		}
 		Ok((folders, files))
 	}
@@ -176,61 +309,145 @@ impl Client {
 	}
 
 	//https://help.aliyun.com/zh/oss/developer-reference/putobject
-	pub async fn put_object<T: Into<bytes::Bytes>>(&self, object_name: &str, bytes: T) -> anyhow::Result<reqwest::header::HeaderMap> {
+	/// 开启 CRC64 校验时, 与 OSS 返回的 CRC64 不一致返回 `Crc64MismatchError`
+	pub async fn put_object<T: Into<bytes::Bytes>>(&self, object_name: &str, bytes: T, options: Option<&crate::PutObjectOptions>) -> anyhow::Result<crate::PutObjectOutput> {
 		let object_name = self.oss_config.get_object_name(object_name);
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::PUT, Some(bytes.into()))?;
-		request.url_mut().set_path(object_name.as_ref());
-		self.oss_config.sign_header_request(&mut request)?;
+		let bytes: bytes::Bytes = bytes.into();
+		let crc64 = if self.oss_config.enable_crc64 { Some(crate::Crc64::checksum(&bytes)) } else { None };
+		let mut request = self.oss_config.get_object_request(reqwest::Method::PUT, object_name.as_ref(), Some(bytes))?;
+		if let Some(options) = options {
+			options.apply(request.headers_mut())?;
+		}
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
+		let response = self.send(request).await?;
+		if let Some(crc64) = crc64 {
+			crate::types::crc64::verify(crc64, response.headers())?;
 		}
-		Ok(response.headers().clone())
+		let mut output = crate::PutObjectOutput::new_from_headers(response.headers().clone())?;
+		if options.is_some_and(|options| options.callback.is_some()) {
+			match read_callback_body(response).await? {
+				Ok(callback_body) => output.callback_body = Some(callback_body),
+				Err(callback_error) => output.callback_error = Some(callback_error),
+			}
+		}
+		Ok(output)
 	}
-	pub async fn put_object_stream<S>(&self, object_name: &str, stream: S) -> anyhow::Result<reqwest::header::HeaderMap>
+	pub async fn put_object_stream<S>(&self, object_name: &str, stream: S, options: Option<&crate::PutObjectOptions>) -> anyhow::Result<crate::PutObjectOutput>
 	where
 		S: futures::stream::Stream<Item = reqwest::Result<bytes::Bytes>> + Send + Sync + 'static,
 	{
 		let object_name = self.oss_config.get_object_name(object_name);
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::PUT, None)?;
-		request.url_mut().set_path(object_name.as_ref());
-		*request.body_mut() = Some(reqwest::Body::wrap_stream(stream));
-		self.oss_config.sign_header_request(&mut request)?;
+		let mut request = self.oss_config.get_object_request(reqwest::Method::PUT, object_name.as_ref(), None)?;
+		if let Some(content_type) = self.oss_config.get_content_type(Some(object_name.as_ref()), None) {
+			request.headers_mut().insert(reqwest::header::CONTENT_TYPE, content_type.try_into()?);
+		}
+		if let Some(options) = options {
+			options.apply(request.headers_mut())?;
+		}
+		let crc64 = std::sync::Arc::new(std::sync::Mutex::new(crate::Crc64::new()));
+		*request.body_mut() = Some(reqwest::Body::wrap_stream(crate::types::crc64::hash_stream(stream, crc64.clone())));
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
+		let response = self.send(request).await?;
+		if self.oss_config.enable_crc64 {
+			crate::types::crc64::verify(crc64.lock().unwrap().finalize(), response.headers())?;
 		}
-		Ok(response.headers().clone())
+		let mut output = crate::PutObjectOutput::new_from_headers(response.headers().clone())?;
+		if options.is_some_and(|options| options.callback.is_some()) {
+			match read_callback_body(response).await? {
+				Ok(callback_body) => output.callback_body = Some(callback_body),
+				Err(callback_error) => output.callback_error = Some(callback_error),
+			}
+		}
+		Ok(output)
 	}
 
 	// https://help.aliyun.com/zh/oss/developer-reference/getobject
-	pub async fn get_object(&self, object_name: &str) -> anyhow::Result<(bytes::Bytes, reqwest::header::HeaderMap)> {
+	/// conditions 不满足时返回 NotModified(304) 或 PreconditionFailed(412) 错误
+	pub async fn get_object(&self, object_name: &str, conditions: Option<&crate::ConditionalOptions>) -> anyhow::Result<(bytes::Bytes, reqwest::header::HeaderMap)> {
 		let object_name = self.oss_config.get_object_name(object_name);
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::GET, None)?;
-		request.url_mut().set_path(object_name.as_ref());
-		self.oss_config.sign_header_request(&mut request)?;
+		let mut request = self.oss_config.get_object_request(reqwest::Method::GET, object_name.as_ref(), None)?;
+		if let Some(conditions) = conditions {
+			conditions.apply(request.headers_mut(), "")?;
+		}
+
+		let response = self.send(request).await?;
+		let headers = response.headers().clone();
+		let bytes = response.bytes().await?;
+		if self.oss_config.enable_crc64 {
+			crate::types::crc64::verify(crate::Crc64::checksum(&bytes), &headers)?;
+		}
+		Ok((bytes, headers))
+	}
+	/// # 以流的方式读取对象
+	/// 响应体不会被缓存到内存中, 适合大对象; 开启 CRC64 校验时, 校验失败会作为流的最后一项返回
+	pub async fn get_object_stream(&self, object_name: &str, conditions: Option<&crate::ConditionalOptions>) -> anyhow::Result<(impl futures::Stream<Item = anyhow::Result<bytes::Bytes>> + Send + 'static, reqwest::header::HeaderMap)> {
+		let object_name = self.oss_config.get_object_name(object_name);
+		let mut request = self.oss_config.get_object_request(reqwest::Method::GET, object_name.as_ref(), None)?;
+		if let Some(conditions) = conditions {
+			conditions.apply(request.headers_mut(), "")?;
+		}
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
+		let response = self.send(request).await?;
+		let headers = response.headers().clone();
+		use futures::StreamExt;
+		let verify_headers = if self.oss_config.enable_crc64 { Some(headers.clone()) } else { None };
+		let stream = futures::stream::try_unfold((Box::pin(response.bytes_stream()), crate::Crc64::new(), verify_headers), |(mut stream, mut crc64, verify_headers)| async move {
+			match stream.next().await {
+				Some(bytes) => {
+					let bytes = bytes?;
+					crc64.update(&bytes);
+					anyhow::Ok(Some((bytes, (stream, crc64, verify_headers))))
+				}
+				None => {
+					if let Some(headers) = &verify_headers {
+						crate::types::crc64::verify(crc64.finalize(), headers)?;
+					}
+					Ok(None)
+				}
+			}
+		});
+		Ok((stream, headers))
+	}
+	/// # 读取对象的指定范围
+	/// 使用 `x-oss-range-behavior: standard`, 范围超出对象大小时返回 InvalidRange 错误而不是整个对象;
+	/// end 超出对象大小时只返回到对象末尾, 以 ContentRange 为准
+	/// ```no_run
+	/// # async fn run(oss_client: ali_oss::Client) -> anyhow::Result<()> {
+	/// let (bytes, content_range, _headers) = oss_client.get_object_range("file.txt", 0..100, None).await?;
+	/// let (tail, _, _) = oss_client.get_object_range("file.txt", 100.., None).await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn get_object_range<R: std::ops::RangeBounds<u64>>(&self, object_name: &str, range: R, conditions: Option<&crate::ConditionalOptions>) -> anyhow::Result<(bytes::Bytes, crate::ContentRange, reqwest::header::HeaderMap)> {
+		let object_name = self.oss_config.get_object_name(object_name);
+		let mut request = self.oss_config.get_object_request(reqwest::Method::GET, object_name.as_ref(), None)?;
+		if let Some(conditions) = conditions {
+			conditions.apply(request.headers_mut(), "")?;
 		}
+		request.headers_mut().insert(reqwest::header::RANGE, crate::types::content_range::to_range_header(&range)?.try_into()?);
+		request.headers_mut().insert("x-oss-range-behavior", "standard".try_into()?);
+
+		let response = self.send(request).await?;
 		let headers = response.headers().clone();
-		Ok((response.bytes().await?, headers))
+		let bytes = response.bytes().await?;
+		let content_range = match headers.get(reqwest::header::CONTENT_RANGE) {
+			Some(content_range) => crate::ContentRange::parse(content_range.to_str()?)?,
+			// 未返回 206 时响应体为整个对象
+			None => crate::ContentRange::new_from_len(bytes.len() as u64),
+		};
+		// 响应头中的 CRC64 是整个对象的, 只有范围覆盖整个对象时才能校验
+		if self.oss_config.enable_crc64 && content_range.start == 0 && content_range.total == Some(bytes.len() as u64) {
+			crate::types::crc64::verify(crate::Crc64::checksum(&bytes), &headers)?;
+		}
+		Ok((bytes, content_range, headers))
 	}
 
 	// https://help.aliyun.com/zh/oss/developer-reference/deleteobject
 	pub async fn delete_object(&self, object_name: &str) -> anyhow::Result<()> {
 		let object_name = self.oss_config.get_object_name(object_name);
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::DELETE, None)?;
-		request.url_mut().set_path(object_name.as_ref());
-		self.oss_config.sign_header_request(&mut request)?;
+		let request = self.oss_config.get_object_request(reqwest::Method::DELETE, object_name.as_ref(), None)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
+		self.send(request).await?;
 		Ok(())
 	}
 	// https://help.aliyun.com/zh/oss/developer-reference/deletemultipleobjects
@@ -257,119 +474,130 @@ impl Client {
 		request.url_mut().set_query(Some(OBJECT_META));
 		request.headers_mut().insert("Content-Type", "application/xml".try_into()?);
 		request.body_mut().replace(reqwest::Body::from(xml_body));
-		self.oss_config.sign_header_request(&mut request)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
+		self.send(request).await?;
 		Ok(())
 	}
 	// https://help.aliyun.com/zh/oss/developer-reference/copyobject
-	pub async fn copy_object(&self, dest_object_name: &str, source_object_name: &str) -> anyhow::Result<reqwest::header::HeaderMap> {
+	pub async fn copy_object(&self, dest_object_name: &str, source_object_name: &str, options: Option<&crate::CopyObjectOptions>) -> anyhow::Result<crate::CopyObjectOutput> {
 		let dest_object_name = self.oss_config.get_object_name(dest_object_name);
 		let source_object_name = self.oss_config.get_object_name(source_object_name);
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::PUT, None)?;
-		request.url_mut().set_path(dest_object_name.as_ref());
+		let mut request = self.oss_config.get_object_request(reqwest::Method::PUT, dest_object_name.as_ref(), None)?;
 		request.headers_mut().insert("x-oss-copy-source", format!("/{}/{}", self.oss_config.bucket_name, source_object_name).try_into()?);
-		self.oss_config.sign_header_request(&mut request)?;
-
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
+		if let Some(options) = options {
+			options.source_conditions.apply(request.headers_mut(), "x-oss-copy-source-")?;
+			options.put_options.apply(request.headers_mut())?;
+			if let Some(metadata_directive) = options.metadata_directive {
+				request.headers_mut().insert("x-oss-metadata-directive", metadata_directive.as_str().try_into()?);
+			}
+			if let Some(tagging_directive) = options.tagging_directive {
+				request.headers_mut().insert("x-oss-tagging-directive", tagging_directive.as_str().try_into()?);
+			}
 		}
-		Ok(response.headers().clone())
+
+		let response = self.send(request).await?;
+		let headers = response.headers().clone();
+		let xml_data = response.text().await?;
+		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
+		crate::CopyObjectOutput::new_from_xml_node(doc.root_element(), headers)
 	}
 
 	// https://help.aliyun.com/zh/oss/developer-reference/appendobject
-	pub async fn append_object<T: Into<bytes::Bytes>>(&self, object_name: &str, bytes: T, position: usize) -> anyhow::Result<reqwest::header::HeaderMap> {
+	/// options 只在第一次追加(position 为 0)时生效.
+	/// OSS 返回的是整个对象的 CRC64, 只有第一次追加时才能与本次内容比较
+	pub async fn append_object<T: Into<bytes::Bytes>>(&self, object_name: &str, bytes: T, position: usize, options: Option<&crate::PutObjectOptions>) -> anyhow::Result<crate::PutObjectOutput> {
 		let object_name = self.oss_config.get_object_name(object_name);
 		static APPEND: &str = "append";
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::POST, Some(bytes.into()))?;
-		request.url_mut().set_path(object_name.as_ref());
-		request.headers_mut().insert("position", position.try_into()?);
+		let bytes: bytes::Bytes = bytes.into();
+		let crc64 = if self.oss_config.enable_crc64 && position == 0 { Some(crate::Crc64::checksum(&bytes)) } else { None };
+		let mut request = self.oss_config.get_object_request(reqwest::Method::POST, object_name.as_ref(), Some(bytes))?;
+		if let Some(options) = options {
+			options.apply(request.headers_mut())?;
+		}
+		request.headers_mut().insert("position", position.into());
 		request.url_mut().set_query(Some(APPEND));
 		request.url_mut().query_pairs_mut().append_pair("position", position.to_string().as_str());
-		self.oss_config.sign_header_request(&mut request)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
+		let response = self.send(request).await?;
+		if let Some(crc64) = crc64 {
+			crate::types::crc64::verify(crc64, response.headers())?;
 		}
-		Ok(response.headers().clone())
+		crate::PutObjectOutput::new_from_headers(response.headers().clone())
 	}
 
 	// https://help.aliyun.com/zh/oss/developer-reference/headobject
-	pub async fn head_object(&self, object_name: &str) -> anyhow::Result<reqwest::header::HeaderMap> {
+	pub async fn head_object(&self, object_name: &str, conditions: Option<&crate::ConditionalOptions>) -> anyhow::Result<crate::ObjectMeta> {
 		let object_name = self.oss_config.get_object_name(object_name);
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::HEAD, None)?;
-		request.url_mut().set_path(object_name.as_ref());
-		self.oss_config.sign_header_request(&mut request)?;
-
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			let encoded_error_message = response.headers().get("x-oss-err").ok_or(anyhow::anyhow!("x-oss-err header not found"))?.to_str()?.to_string();
-			let err_message = String::from_utf8(BASE64_STANDARD.decode(encoded_error_message.as_bytes())?)?;
-			return Err(anyhow::anyhow!(err_message));
+		let mut request = self.oss_config.get_object_request(reqwest::Method::HEAD, object_name.as_ref(), None)?;
+		if let Some(conditions) = conditions {
+			conditions.apply(request.headers_mut(), "")?;
 		}
-		Ok(response.headers().clone())
+
+		let response = self.send(request).await?;
+		crate::ObjectMeta::new_from_headers(response.headers().clone())
 	}
 
 	// https://help.aliyun.com/zh/oss/developer-reference/getobjectmeta
-	pub async fn get_object_meta(&self, object_name: &str) -> anyhow::Result<reqwest::header::HeaderMap> {
+	pub async fn get_object_meta(&self, object_name: &str, conditions: Option<&crate::ConditionalOptions>) -> anyhow::Result<crate::ObjectMeta> {
 		let object_name = self.oss_config.get_object_name(object_name);
 		static OBJECT_META: &str = "objectMeta";
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::HEAD, None)?;
-		request.url_mut().set_path(object_name.as_ref());
+		let mut request = self.oss_config.get_object_request(reqwest::Method::HEAD, object_name.as_ref(), None)?;
 		request.url_mut().set_query(Some(OBJECT_META));
-		self.oss_config.sign_header_request(&mut request)?;
-
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			let encoded_error_message = response.headers().get("x-oss-err").ok_or(anyhow::anyhow!("x-oss-err header not found"))?.to_str()?.to_string();
-			let err_message = String::from_utf8(BASE64_STANDARD.decode(encoded_error_message.as_bytes())?)?;
-			return Err(anyhow::anyhow!(err_message));
+		if let Some(conditions) = conditions {
+			conditions.apply(request.headers_mut(), "")?;
 		}
-		Ok(response.headers().clone())
+
+		let response = self.send(request).await?;
+		crate::ObjectMeta::new_from_headers(response.headers().clone())
 	}
 	// 只能判断文件是否存在, 无法判断文件夹
 	pub async fn is_object_exist(&self, object_name: &str) -> anyhow::Result<bool> {
-		match self.get_object_meta(object_name).await {
+		match self.get_object_meta(object_name, None).await {
 			Ok(_) => Ok(true),
-			Err(e) if e.to_string().contains("NoSuchKey") => Ok(false),
-			Err(e) => Err(e),
+			Err(e) => match e.downcast_ref::<OssError>() {
+				Some(oss_error) if oss_error.code == OssErrorCode::NoSuchKey => Ok(false),
+				_ => Err(e),
+			},
 		}
 	}
 
 	// https://www.alibabacloud.com/help/zh/oss/developer-reference/ddd-signatures-to-urls
 	pub async fn sign_object(&self, object_name: &str, expires_duration: std::time::Duration) -> anyhow::Result<String> {
-		let object_name = self.oss_config.get_decoded_object_name(object_name);
-		let expires_time = {
-			let datetime: chrono::DateTime<chrono::Utc> = std::time::SystemTime::now().into();
-			let expires_time = datetime + chrono::Duration::from_std(expires_duration)?;
-			expires_time
-		};
-		let mut object_url = {
-			let host = format!("{}.{}.aliyuncs.com", self.oss_config.bucket_name, self.oss_config.bucket_location.as_str());
-			let object_link = format!("https://{}/{}", host, object_name);
-			reqwest::Url::parse(&object_link)?
-		};
-		let signature_string = crate::types::ParamSignature::new(
-			reqwest::Method::GET,
-			None,
-			None,
-			expires_time.clone(),
-			crate::types::CanonicalizedHeaders::new(None),
-			crate::types::CanonicalizedResource::new(format!("/{}/{}", self.oss_config.bucket_name, object_name)),
-		)
-		.get_signature_string(&self.oss_config);
-		object_url
-			.query_pairs_mut()
-			.append_pair("OSSAccessKeyId", &self.oss_config.access_key_id)
-			.append_pair("Expires", expires_time.timestamp().to_string().as_str())
-			.append_pair("Signature", &signature_string);
-
-		Ok(object_url.to_string())
+		self.presign_object(object_name, expires_duration, &crate::PresignOptions::new()).await
+	}
+	/// # 生成任意请求的预签名 URL
+	/// 按客户端的签名版本(V1/V4)签名; 签名包含的 Content-Type, Content-MD5 与 `x-oss-*` 请求头在使用 URL 时需要一并发送
+	/// ```no_run
+	/// # async fn run(oss_client: ali_oss::Client) -> anyhow::Result<()> {
+	/// let options = ali_oss::PresignOptions::new().method(reqwest::Method::PUT).content_type("text/plain");
+	/// let url = oss_client.presign_object("1.txt", std::time::Duration::from_secs(600), &options).await?;
+	/// // curl -X PUT -H "Content-Type: text/plain" --data-binary @1.txt "{url}"
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn presign_object(&self, object_name: &str, expires_duration: std::time::Duration, options: &crate::PresignOptions) -> anyhow::Result<String> {
+		let object_name = self.oss_config.get_object_name(object_name);
+		let credentials = self.credentials_provider.get_credentials().await?;
+		let url = self.oss_config.presign_object_url(object_name.as_ref(), expires_duration, options, &credentials)?;
+		Ok(url.to_string())
+	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/postobject
+	/// # 生成浏览器表单直传所需的字段
+	/// ```
+	/// # #[tokio::main(flavor = "current_thread")]
+	/// # async fn main() -> anyhow::Result<()> {
+	/// let oss_client = ali_oss::Client::new("id", "secret", "bucket", "oss-cn-hangzhou", "", false);
+	/// let policy = ali_oss::PostPolicy::new().key_prefix("uploads/").content_length_range(0, 1024 * 1024);
+	/// let form = oss_client.post_object_form(&policy).await?;
+	/// assert_eq!(form.url, "https://bucket.oss-cn-hangzhou.aliyuncs.com/");
+	/// assert!(form.fields.contains_key("policy") && form.fields.contains_key("Signature"));
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn post_object_form(&self, policy: &crate::PostPolicy) -> anyhow::Result<crate::PostObjectForm> {
+		let credentials = self.credentials_provider.get_credentials().await?;
+		self.oss_config.sign_post_policy(policy, &credentials)
 	}
 }
 
@@ -379,16 +607,11 @@ impl Client {
 		static SYMLINK: &str = "symlink";
 		let symlink_object_name = self.oss_config.get_object_name(symlink_object_name);
 		let target_object_name = self.oss_config.get_encoded_object_name(target_object_name);
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::PUT, None)?;
-		request.url_mut().set_path(symlink_object_name.as_ref());
+		let mut request = self.oss_config.get_object_request(reqwest::Method::PUT, symlink_object_name.as_ref(), None)?;
 		request.headers_mut().insert("x-oss-symlink-target", target_object_name.as_ref().try_into()?);
 		request.url_mut().set_query(Some(SYMLINK));
-		self.oss_config.sign_header_request(&mut request)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
+		self.send(request).await?;
 		Ok(())
 	}
 
@@ -396,15 +619,208 @@ impl Client {
 	pub async fn get_symlink(&self, object_name: &str) -> anyhow::Result<String> {
 		let object_name = self.oss_config.get_object_name(object_name);
 		static SYMLINK: &str = "symlink";
-		let mut request = self.oss_config.get_bucket_request(reqwest::Method::GET, None)?;
-		request.url_mut().set_path(object_name.as_ref());
+		let mut request = self.oss_config.get_object_request(reqwest::Method::GET, object_name.as_ref(), None)?;
 		request.url_mut().set_query(Some(SYMLINK));
-		self.oss_config.sign_header_request(&mut request)?;
 
-		let response = self.oss_config.get_request_builder(request)?.send().await?;
-		if !response.status().is_success() {
-			return Err(anyhow::anyhow!(response.text().await?));
-		}
+		let response = self.send(request).await?;
 		Ok(response.headers().get("x-oss-symlink-target").ok_or(anyhow::anyhow!("no symlink target"))?.to_str()?.to_owned())
 	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/putobjectacl
+	/// acl 为 `Acl::Default` 时继承 bucket 的权限
+	pub async fn put_object_acl(&self, object_name: &str, acl: crate::Acl) -> anyhow::Result<()> {
+		let object_name = self.oss_config.get_object_name(object_name);
+		static ACL: &str = "acl";
+		let mut request = self.oss_config.get_object_request(reqwest::Method::PUT, object_name.as_ref(), None)?;
+		request.url_mut().set_query(Some(ACL));
+		request.headers_mut().insert("x-oss-object-acl", acl.as_str().try_into()?);
+
+		self.send(request).await?;
+		Ok(())
+	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/getobjectacl
+	pub async fn get_object_acl(&self, object_name: &str) -> anyhow::Result<crate::AccessControlPolicy> {
+		let object_name = self.oss_config.get_object_name(object_name);
+		static ACL: &str = "acl";
+		let mut request = self.oss_config.get_object_request(reqwest::Method::GET, object_name.as_ref(), None)?;
+		request.url_mut().set_query(Some(ACL));
+
+		let response = self.send(request).await?;
+		let xml_data = response.text().await?;
+		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
+		crate::AccessControlPolicy::new_from_xml_node(doc.root_element())
+	}
+}
+
+impl Client {
+	// https://help.aliyun.com/zh/oss/developer-reference/initiatemultipartupload
+	pub async fn initiate_multipart_upload(&self, object_name: &str) -> anyhow::Result<crate::InitiateMultipartUploadResult> {
+		static UPLOADS: &str = "uploads";
+		let object_name = self.oss_config.get_object_name(object_name);
+		let mut request = self.oss_config.get_object_request(reqwest::Method::POST, object_name.as_ref(), None)?;
+		request.url_mut().set_query(Some(UPLOADS));
+		// 对象的 Content-Type 在初始化时确定, 只能根据扩展名推断
+		if let Some(content_type) = self.oss_config.get_content_type(Some(object_name.as_ref()), None) {
+			request.headers_mut().insert(reqwest::header::CONTENT_TYPE, content_type.try_into()?);
+		}
+
+		let response = self.send(request).await?;
+		let xml_data = response.text().await?;
+		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
+		crate::InitiateMultipartUploadResult::new_from_xml_node(doc.root_element())
+	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/uploadpart
+	/// # 上传分片
+	/// part_number 取值 1~10000, 除最后一个分片外每个分片不小于 100KB
+	pub async fn upload_part<T: Into<bytes::Bytes>>(&self, object_name: &str, upload_id: &str, part_number: u32, bytes: T) -> anyhow::Result<crate::Part> {
+		let object_name = self.oss_config.get_object_name(object_name);
+		let bytes: bytes::Bytes = bytes.into();
+		let crc64 = if self.oss_config.enable_crc64 { Some(crate::Crc64::checksum(&bytes)) } else { None };
+		let mut request = self.oss_config.get_object_request(reqwest::Method::PUT, object_name.as_ref(), Some(bytes))?;
+		request.url_mut().query_pairs_mut().append_pair("partNumber", part_number.to_string().as_str()).append_pair("uploadId", upload_id);
+
+		let response = self.send(request).await?;
+		if let Some(crc64) = crc64 {
+			crate::types::crc64::verify(crc64, response.headers())?;
+		}
+		crate::Part::new_from_headers(part_number, response.headers())
+	}
+	pub async fn upload_part_stream<S>(&self, object_name: &str, upload_id: &str, part_number: u32, stream: S) -> anyhow::Result<crate::Part>
+	where
+		S: futures::stream::Stream<Item = reqwest::Result<bytes::Bytes>> + Send + Sync + 'static,
+	{
+		let object_name = self.oss_config.get_object_name(object_name);
+		let mut request = self.oss_config.get_object_request(reqwest::Method::PUT, object_name.as_ref(), None)?;
+		request.url_mut().query_pairs_mut().append_pair("partNumber", part_number.to_string().as_str()).append_pair("uploadId", upload_id);
+		let crc64 = std::sync::Arc::new(std::sync::Mutex::new(crate::Crc64::new()));
+		*request.body_mut() = Some(reqwest::Body::wrap_stream(crate::types::crc64::hash_stream(stream, crc64.clone())));
+
+		let response = self.send(request).await?;
+		if self.oss_config.enable_crc64 {
+			crate::types::crc64::verify(crc64.lock().unwrap().finalize(), response.headers())?;
+		}
+		crate::Part::new_from_headers(part_number, response.headers())
+	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/completemultipartupload
+	/// # 完成分片上传
+	/// parts 为 None 时使用 `x-oss-complete-all: yes`, 由 OSS 按分片号合并全部已上传的分片;
+	/// 设置了 callback 时回调服务器返回的内容在 `callback_body` 中, 回调失败时对象已经合并, 错误在 `callback_error` 中
+	pub async fn complete_multipart_upload(&self, object_name: &str, upload_id: &str, parts: Option<&[crate::Part]>, callback: Option<&crate::CallbackOptions>) -> anyhow::Result<crate::CompleteMultipartUploadResult> {
+		let object_name = self.oss_config.get_object_name(object_name);
+		let mut request = match parts {
+			Some(parts) => {
+				let mut parts = parts.iter().collect::<Vec<_>>();
+				parts.sort_by_key(|part| part.part_number);
+				let xml_parts = parts
+					.into_iter()
+					.map(|part| format!("<Part><PartNumber>{}</PartNumber><ETag>\"{}\"</ETag></Part>", part.part_number, part.etag))
+					.collect::<Vec<String>>()
+					.join("");
+				let xml_body = format!(r#"<?xml version="1.0" encoding="UTF-8"?><CompleteMultipartUpload>{}</CompleteMultipartUpload>"#, xml_parts);
+				let mut request = self.oss_config.get_object_request(reqwest::Method::POST, object_name.as_ref(), Some(xml_body.into()))?;
+				request.headers_mut().insert("Content-Type", "application/xml".try_into()?);
+				request
+			}
+			None => {
+				let mut request = self.oss_config.get_object_request(reqwest::Method::POST, object_name.as_ref(), None)?;
+				request.headers_mut().insert("x-oss-complete-all", "yes".try_into()?);
+				request
+			}
+		};
+		request.url_mut().query_pairs_mut().append_pair("uploadId", upload_id);
+		if let Some(callback) = callback {
+			callback.apply(request.headers_mut())?;
+		}
+
+		let response = self.send(request).await?;
+		let headers = response.headers().clone();
+		if callback.is_some() {
+			let mut result = crate::CompleteMultipartUploadResult::new_from_headers(&self.oss_config.bucket_name, object_name.as_ref(), &headers)?;
+			match read_callback_body(response).await? {
+				Ok(callback_body) => result.callback_body = Some(callback_body),
+				Err(callback_error) => result.callback_error = Some(callback_error),
+			}
+			return Ok(result);
+		}
+		let xml_data = response.text().await?;
+		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
+		crate::CompleteMultipartUploadResult::new_from_xml_node(doc.root_element(), &headers)
+	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/abortmultipartupload
+	pub async fn abort_multipart_upload(&self, object_name: &str, upload_id: &str) -> anyhow::Result<()> {
+		let object_name = self.oss_config.get_object_name(object_name);
+		let mut request = self.oss_config.get_object_request(reqwest::Method::DELETE, object_name.as_ref(), None)?;
+		request.url_mut().query_pairs_mut().append_pair("uploadId", upload_id);
+
+		self.send(request).await?;
+		Ok(())
+	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/listparts
+	/// # 列举已上传的分片
+	/// 结果未列举完时使用 next_part_number_marker 继续请求
+	pub async fn list_parts(&self, object_name: &str, upload_id: &str, part_number_marker: Option<u32>, max_parts: Option<u32>) -> anyhow::Result<crate::ListPartsResult> {
+		let object_name = self.oss_config.get_object_name(object_name);
+		let mut request = self.oss_config.get_object_request(reqwest::Method::GET, object_name.as_ref(), None)?;
+		{
+			let mut query = request.url_mut().query_pairs_mut();
+			query.append_pair("uploadId", upload_id);
+			if let Some(part_number_marker) = part_number_marker {
+				query.append_pair("part-number-marker", part_number_marker.to_string().as_str());
+			}
+			if let Some(max_parts) = max_parts {
+				query.append_pair("max-parts", max_parts.to_string().as_str());
+			}
+		}
+
+		let response = self.send(request).await?;
+		let xml_data = response.text().await?;
+		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
+		crate::ListPartsResult::new_from_xml_node(doc.root_element())
+	}
+
+	// https://help.aliyun.com/zh/oss/developer-reference/listmultipartuploads
+	/// # 列举进行中的分片上传事件
+	/// prefix 与 key_marker 会拼接 OssConfig 中的 path
+	pub async fn list_multipart_uploads(&self, options: &crate::ListMultipartUploadsOptions) -> anyhow::Result<crate::ListMultipartUploadsResult> {
+		static UPLOADS: &str = "uploads";
+		let mut request = self.oss_config.get_bucket_request(reqwest::Method::GET, None)?;
+		request.url_mut().set_query(Some(UPLOADS));
+		{
+			let mut query = request.url_mut().query_pairs_mut();
+			if let Some(prefix) = &options.prefix {
+				query.append_pair("prefix", self.oss_config.get_object_name(prefix).as_ref());
+			}
+			if let Some(delimiter) = &options.delimiter {
+				query.append_pair("delimiter", delimiter);
+			}
+			if let Some(key_marker) = &options.key_marker {
+				query.append_pair("key-marker", self.oss_config.get_object_name(key_marker).as_ref());
+			}
+			if let Some(upload_id_marker) = &options.upload_id_marker {
+				query.append_pair("upload-id-marker", upload_id_marker);
+			}
+			if let Some(max_uploads) = options.max_uploads {
+				query.append_pair("max-uploads", max_uploads.to_string().as_str());
+			}
+		}
+
+		let response = self.send(request).await?;
+		let xml_data = response.text().await?;
+		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_data)?;
+		crate::ListMultipartUploadsResult::new_from_xml_node(doc.root_element())
+	}
+}
+
+/// # 读取回调服务器返回的内容
+/// 上传成功但回调失败时 OSS 返回 203 与 CallbackFailed 错误, 此时对象已经写入, 不作为请求失败处理
+async fn read_callback_body(response: reqwest::Response) -> anyhow::Result<Result<String, OssError>> {
+	if response.status() == reqwest::StatusCode::NON_AUTHORITATIVE_INFORMATION {
+		return Ok(Err(OssError::from_response(response).await?));
+	}
+	Ok(Ok(response.text().await?))
 }
@@ -0,0 +1,207 @@
+use crate::types::OssConfig;
+use std::time::Duration;
+
+/// # 构造 `Client`
+/// 同一个 `Client` 内的所有请求共用一个 `reqwest::Client`, 以复用连接池
+/// ```
+/// # use ali_oss::{Client, OssConfig};
+/// # use std::time::Duration;
+/// let oss_config = OssConfig::new("id".to_owned(), "secret".to_owned(), "bucket".to_owned(), "oss-cn-hangzhou".to_owned(), "".to_owned(), false);
+/// let client = Client::builder(oss_config).connect_timeout(Duration::from_secs(5)).pool_max_idle_per_host(32).build().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ClientBuilder {
+	oss_config: OssConfig,
+	connect_timeout: Option<Duration>,
+	read_timeout: Option<Duration>,
+	timeout: Option<Duration>,
+	pool_max_idle_per_host: Option<usize>,
+	pool_idle_timeout: Option<Duration>,
+	http1_only: bool,
+	http2_prior_knowledge: bool,
+	proxy: Option<reqwest::Proxy>,
+	no_proxy: bool,
+	user_agent: Option<String>,
+	root_certificates: Vec<reqwest::Certificate>,
+	tls_built_in_root_certs: bool,
+	http_client: Option<reqwest::Client>,
+	retry_policy: std::sync::Arc<dyn crate::RetryPolicy>,
+	credentials_provider: Option<std::sync::Arc<dyn crate::CredentialsProvider>>,
+}
+
+impl ClientBuilder {
+	pub fn new(oss_config: OssConfig) -> Self {
+		Self {
+			oss_config,
+			connect_timeout: None,
+			read_timeout: None,
+			timeout: None,
+			pool_max_idle_per_host: None,
+			pool_idle_timeout: None,
+			http1_only: false,
+			http2_prior_knowledge: false,
+			proxy: None,
+			no_proxy: false,
+			user_agent: None,
+			root_certificates: Vec::new(),
+			tls_built_in_root_certs: true,
+			http_client: None,
+			retry_policy: std::sync::Arc::new(crate::ExponentialBackoff::default()),
+			credentials_provider: None,
+		}
+	}
+	/// 建立连接的超时时间
+	pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+		self.connect_timeout = Some(timeout);
+		self
+	}
+	/// 两次读取数据之间的超时时间, 适合大文件传输
+	pub fn read_timeout(mut self, timeout: Duration) -> Self {
+		self.read_timeout = Some(timeout);
+		self
+	}
+	/// 整个请求(含响应体)的超时时间
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+	pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+		self.pool_max_idle_per_host = Some(max);
+		self
+	}
+	pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+		self.pool_idle_timeout = Some(timeout);
+		self
+	}
+	pub fn http1_only(mut self) -> Self {
+		self.http1_only = true;
+		self
+	}
+	pub fn http2_prior_knowledge(mut self) -> Self {
+		self.http2_prior_knowledge = true;
+		self
+	}
+	pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+		self.proxy = Some(proxy);
+		self
+	}
+	/// 忽略系统代理环境变量
+	pub fn no_proxy(mut self) -> Self {
+		self.no_proxy = true;
+		self
+	}
+	pub fn user_agent<T: ToString>(mut self, user_agent: T) -> Self {
+		self.user_agent = Some(user_agent.to_string());
+		self
+	}
+	pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+		self.root_certificates.push(certificate);
+		self
+	}
+	/// 是否信任系统内置的根证书, 默认为 true
+	pub fn tls_built_in_root_certs(mut self, enabled: bool) -> Self {
+		self.tls_built_in_root_certs = enabled;
+		self
+	}
+	/// 使用外部构造的 `reqwest::Client`, 此时其他传输层配置不再生效
+	pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+		self.http_client = Some(http_client);
+		self
+	}
+	/// 重试策略, 默认为 `ExponentialBackoff::default()`, 使用 `NoRetry` 关闭重试
+	pub fn retry_policy<T: crate::RetryPolicy + 'static>(mut self, retry_policy: T) -> Self {
+		self.retry_policy = std::sync::Arc::new(retry_policy);
+		self
+	}
+	/// STS 临时凭证的安全令牌
+	pub fn security_token<T: ToString>(mut self, security_token: T) -> Self {
+		self.oss_config.security_token = Some(security_token.to_string());
+		self
+	}
+	/// 凭证提供者, 默认使用 OssConfig 中的 access_key; 获取到的凭证会被缓存并在过期前刷新,
+	/// 已经缓存的提供者(`CredentialsProvider::is_cached`, 如 `CachedCredentialsProvider`)不会重复包装
+	pub fn credentials_provider<T: crate::CredentialsProvider + 'static>(mut self, credentials_provider: T) -> Self {
+		self.credentials_provider = Some(std::sync::Arc::new(credentials_provider));
+		self
+	}
+	/// 自定义访问域名, 支持 CNAME 与 path-style
+	pub fn endpoint(mut self, endpoint: crate::Endpoint) -> Self {
+		self.oss_config.endpoint = Some(endpoint);
+		self
+	}
+	/// 签名版本, 默认为 V1
+	pub fn signature_version(mut self, signature_version: crate::SignatureVersion) -> Self {
+		self.oss_config.signature_version = signature_version;
+		self
+	}
+	/// V4 签名时额外参与签名的请求头
+	pub fn additional_signed_headers<T: ToString>(mut self, headers: Vec<T>) -> Self {
+		self.oss_config.additional_signed_headers = headers.into_iter().map(|h| h.to_string().to_lowercase()).collect();
+		self
+	}
+	/// 上传与下载时是否校验 CRC64, 默认为 true; 校验失败返回 `Crc64MismatchError`
+	pub fn enable_crc64(mut self, enabled: bool) -> Self {
+		self.oss_config.enable_crc64 = enabled;
+		self
+	}
+	/// 上传时是否自动设置 Content-Type, 默认为 true
+	pub fn detect_content_type(mut self, enabled: bool) -> Self {
+		self.oss_config.detect_content_type = enabled;
+		self
+	}
+	/// 自定义扩展名对应的 Content-Type, 如 `.content_type("js", "application/javascript")`
+	pub fn content_type<K: ToString, V: ToString>(mut self, extension: K, content_type: V) -> Self {
+		let extension = extension.to_string();
+		self.oss_config.content_types.insert(extension.trim_start_matches('.').to_lowercase(), content_type.to_string());
+		self
+	}
+
+	pub fn build(self) -> anyhow::Result<crate::Client> {
+		let http_client = match self.http_client {
+			Some(http_client) => http_client,
+			None => {
+				let mut builder = reqwest::Client::builder().tls_built_in_root_certs(self.tls_built_in_root_certs);
+				if let Some(timeout) = self.connect_timeout {
+					builder = builder.connect_timeout(timeout);
+				}
+				if let Some(timeout) = self.read_timeout {
+					builder = builder.read_timeout(timeout);
+				}
+				if let Some(timeout) = self.timeout {
+					builder = builder.timeout(timeout);
+				}
+				if let Some(max) = self.pool_max_idle_per_host {
+					builder = builder.pool_max_idle_per_host(max);
+				}
+				if let Some(timeout) = self.pool_idle_timeout {
+					builder = builder.pool_idle_timeout(timeout);
+				}
+				if self.http1_only {
+					builder = builder.http1_only();
+				}
+				if self.http2_prior_knowledge {
+					builder = builder.http2_prior_knowledge();
+				}
+				if self.no_proxy {
+					builder = builder.no_proxy();
+				}
+				if let Some(proxy) = self.proxy {
+					builder = builder.proxy(proxy);
+				}
+				if let Some(user_agent) = self.user_agent {
+					builder = builder.user_agent(user_agent);
+				}
+				for certificate in self.root_certificates {
+					builder = builder.add_root_certificate(certificate);
+				}
+				builder.build()?
+			}
+		};
+		let credentials_provider: std::sync::Arc<dyn crate::CredentialsProvider> = match self.credentials_provider {
+			Some(credentials_provider) if credentials_provider.is_cached() => credentials_provider,
+			Some(credentials_provider) => std::sync::Arc::new(crate::CachedCredentialsProvider::new_from_arc(credentials_provider)),
+			None => std::sync::Arc::new(crate::StaticCredentialsProvider::new_from_credentials(self.oss_config.get_credentials())),
+		};
+		Ok(crate::Client::new_with_parts(self.oss_config, http_client, self.retry_policy, credentials_provider))
+	}
+}
@@ -0,0 +1,152 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::sync::Arc;
+
+/// # 通过 STS AssumeRole 扮演 RAM 角色获取临时凭证
+/// https://help.aliyun.com/zh/ram/developer-reference/api-sts-2015-04-01-assumerole
+///
+/// 调用 STS 使用 `source` 提供的凭证签名; 获取到的凭证带有过期时间, 由 `Client` 缓存并在过期前刷新
+/// ```
+/// # use ali_oss::{AssumeRoleCredentialsProvider, Credentials, CredentialsProvider, StaticCredentialsProvider};
+/// # use std::collections::BTreeMap;
+/// # use std::io::{BufRead, BufReader, Read, Write};
+/// # use std::sync::{Arc, Mutex};
+/// // 本地的 STS 替身: 按表单内容返回响应, 记录每个请求的请求行与表单
+/// fn serve(respond: fn(&str) -> (u16, &'static str)) -> (String, Arc<Mutex<Vec<String>>>) {
+///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+///     let url = format!("http://{}", listener.local_addr().unwrap());
+///     let requests = Arc::new(Mutex::new(Vec::new()));
+///     let log = requests.clone();
+///     std::thread::spawn(move || {
+///         for stream in listener.incoming() {
+///             let mut stream = stream.unwrap();
+///             let mut reader = BufReader::new(stream.try_clone().unwrap());
+///             let (mut request, mut content_length) = (String::new(), 0);
+///             loop {
+///                 let mut line = String::new();
+///                 reader.read_line(&mut line).unwrap();
+///                 if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+///                     content_length = value.trim().parse().unwrap();
+///                 }
+///                 if line == "\r\n" {
+///                     break;
+///                 }
+///                 request.push_str(&line);
+///             }
+///             let mut form = vec![0u8; content_length];
+///             reader.read_exact(&mut form).unwrap();
+///             let form = String::from_utf8(form).unwrap();
+///             let (status, body) = respond(&form);
+///             log.lock().unwrap().push(format!("{}{}", request.lines().next().unwrap(), form));
+///             write!(stream, "HTTP/1.1 {} OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+///         }
+///     });
+///     (url, requests)
+/// }
+/// fn parse_form(request: &str) -> BTreeMap<String, String> {
+///     let form = request.split_once("HTTP/1.1").unwrap().1;
+///     reqwest::Url::parse(&format!("http://localhost/?{}", form)).unwrap().query_pairs().into_owned().collect()
+/// }
+/// const CREDENTIALS: &str = r#"{"RequestId":"1","Credentials":{"SecurityToken":"token","AccessKeyId":"STS.id","AccessKeySecret":"secret","Expiration":"2015-04-09T11:52:19Z"}}"#;
+/// const NO_PERMISSION: &str = r#"{"RequestId":"2","Code":"NoPermission","Message":"You are not authorized to do this action."}"#;
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let (url, log) = serve(|form| if form.contains("role%2Fdenied") { (403, NO_PERMISSION) } else { (200, CREDENTIALS) });
+/// let source = StaticCredentialsProvider::new("source-id", "source-secret", Some("source-token"));
+/// let provider = AssumeRoleCredentialsProvider::new(source.clone(), "acs:ram::1:role/reader").role_session_name("session").duration_seconds(900).external_id("external").sts_endpoint(&url);
+/// let credentials = provider.get_credentials().await?;
+/// assert_eq!((credentials.access_key_id.as_str(), credentials.security_token.as_deref()), ("STS.id", Some("token")));
+///
+/// // POST 表单带有角色参数, 并使用 source 的凭证签名
+/// let request = log.lock().unwrap()[0].clone();
+/// assert!(request.starts_with("POST / "));
+/// let mut form = parse_form(&request);
+/// assert_eq!(form["Action"], "AssumeRole");
+/// assert_eq!((form["RoleArn"].as_str(), form["RoleSessionName"].as_str(), form["DurationSeconds"].as_str(), form["ExternalId"].as_str()), ("acs:ram::1:role/reader", "session", "900", "external"));
+/// assert_eq!((form["AccessKeyId"].as_str(), form["SecurityToken"].as_str()), ("source-id", "source-token"));
+/// let signature = form["Signature"].clone();
+/// ali_oss::sts::sign_params(&reqwest::Method::POST, &mut form, &Credentials::new("source-id", "source-secret", Some("source-token")));
+/// assert_eq!(form["Signature"], signature);
+///
+/// // STS 返回的错误
+/// let provider = AssumeRoleCredentialsProvider::new(source, "acs:ram::1:role/denied").sts_endpoint(&url);
+/// let message = provider.get_credentials().await.unwrap_err().to_string();
+/// assert!(message.contains("403") && message.contains("NoPermission"), "{}", message);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AssumeRoleCredentialsProvider {
+	source: Arc<dyn super::CredentialsProvider>,
+	role_arn: String,
+	role_session_name: String,
+	duration_seconds: u64,
+	policy: Option<String>,
+	external_id: Option<String>,
+	sts_endpoint: String,
+	http_client: reqwest::Client,
+}
+
+impl AssumeRoleCredentialsProvider {
+	pub fn new<P: super::CredentialsProvider + 'static, T: ToString>(source: P, role_arn: T) -> Self {
+		Self {
+			source: Arc::new(source),
+			role_arn: role_arn.to_string(),
+			role_session_name: format!("ali-oss-{}", chrono::Utc::now().timestamp()),
+			duration_seconds: 3600,
+			policy: None,
+			external_id: None,
+			sts_endpoint: super::sts::STS_ENDPOINT.to_string(),
+			http_client: reqwest::Client::new(),
+		}
+	}
+	pub fn role_session_name<T: ToString>(mut self, role_session_name: T) -> Self {
+		self.role_session_name = role_session_name.to_string();
+		self
+	}
+	/// 凭证有效期, 900 秒到角色允许的最大会话时间
+	pub fn duration_seconds(mut self, duration_seconds: u64) -> Self {
+		self.duration_seconds = duration_seconds;
+		self
+	}
+	/// 进一步限制权限的策略(JSON)
+	pub fn policy<T: ToString>(mut self, policy: T) -> Self {
+		self.policy = Some(policy.to_string());
+		self
+	}
+	pub fn external_id<T: ToString>(mut self, external_id: T) -> Self {
+		self.external_id = Some(external_id.to_string());
+		self
+	}
+	/// STS 地址, 默认为 https://sts.aliyuncs.com
+	pub fn sts_endpoint<T: ToString>(mut self, sts_endpoint: T) -> Self {
+		self.sts_endpoint = sts_endpoint.to_string();
+		self
+	}
+	pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+		self.http_client = http_client;
+		self
+	}
+
+	async fn fetch(&self) -> anyhow::Result<super::Credentials> {
+		let source_credentials = self.source.get_credentials().await?;
+		let mut params = super::sts::common_params("AssumeRole")?;
+		params.insert("RoleArn".to_string(), self.role_arn.clone());
+		params.insert("RoleSessionName".to_string(), self.role_session_name.clone());
+		params.insert("DurationSeconds".to_string(), self.duration_seconds.to_string());
+		if let Some(policy) = &self.policy {
+			params.insert("Policy".to_string(), policy.clone());
+		}
+		if let Some(external_id) = &self.external_id {
+			params.insert("ExternalId".to_string(), external_id.clone());
+		}
+		super::sts::sign_params(&reqwest::Method::POST, &mut params, &source_credentials);
+		super::sts::request_credentials(&self.http_client, &self.sts_endpoint, &params).await
+	}
+}
+
+impl super::CredentialsProvider for AssumeRoleCredentialsProvider {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<super::Credentials>> {
+		self.fetch().boxed()
+	}
+}
@@ -0,0 +1,144 @@
+use futures::future::BoxFuture;
+use futures::lock::Mutex;
+use futures::FutureExt;
+use std::sync::Arc;
+
+/// # 缓存凭证
+/// 带过期时间的凭证在过期前 `refresh_before` 刷新, 刷新失败且旧凭证仍未过期时继续使用旧凭证;
+/// 不带过期时间的凭证每隔 `refresh_interval` 重新获取一次, 以便感知密钥轮换
+/// ```
+/// # use ali_oss::{CachedCredentialsProvider, ChainCredentialsProvider, Credentials, CredentialsProvider};
+/// # use futures::future::BoxFuture;
+/// # use std::sync::atomic::{AtomicUsize, Ordering};
+/// # use std::sync::{Arc, Mutex};
+/// // 记录调用次数, 可以控制返回凭证的过期时间与是否失败
+/// #[derive(Debug, Clone, Default)]
+/// struct FakeProvider {
+///     calls: Arc<AtomicUsize>,
+///     expiration: Arc<Mutex<Option<chrono::DateTime<chrono::Utc>>>>,
+///     fail: Arc<Mutex<bool>>,
+/// }
+/// impl CredentialsProvider for FakeProvider {
+///     fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<Credentials>> {
+///         let calls = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+///         let result = if *self.fail.lock().unwrap() {
+///             Err(anyhow::anyhow!("fetch failed"))
+///         } else {
+///             let credentials = Credentials::new(format!("id-{}", calls), "secret".to_string(), None);
+///             Ok(match *self.expiration.lock().unwrap() {
+///                 Some(expiration) => credentials.with_expiration(expiration),
+///                 None => credentials,
+///             })
+///         };
+///         Box::pin(async move { result })
+///     }
+/// }
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let now = chrono::Utc::now();
+///
+/// // 未临近过期时使用缓存
+/// let fake = FakeProvider::default();
+/// *fake.expiration.lock().unwrap() = Some(now + chrono::Duration::hours(1));
+/// let cached = CachedCredentialsProvider::new(fake.clone());
+/// assert_eq!(cached.get_credentials().await?.access_key_id, "id-1");
+/// assert_eq!(cached.get_credentials().await?.access_key_id, "id-1");
+/// assert_eq!(fake.calls.load(Ordering::SeqCst), 1);
+/// assert!(cached.is_cached() && !fake.is_cached());
+///
+/// // 在 refresh_before 内过期时刷新, 刷新失败但旧凭证未过期时继续使用旧凭证
+/// let fake = FakeProvider::default();
+/// *fake.expiration.lock().unwrap() = Some(now + chrono::Duration::minutes(3));
+/// let cached = CachedCredentialsProvider::new(fake.clone());
+/// assert_eq!(cached.get_credentials().await?.access_key_id, "id-1");
+/// assert_eq!(cached.get_credentials().await?.access_key_id, "id-2");
+/// *fake.fail.lock().unwrap() = true;
+/// assert_eq!(cached.get_credentials().await?.access_key_id, "id-2");
+/// assert_eq!(fake.calls.load(Ordering::SeqCst), 3);
+///
+/// // 已经过期且刷新失败时返回错误
+/// *fake.expiration.lock().unwrap() = Some(now - chrono::Duration::minutes(1));
+/// *fake.fail.lock().unwrap() = false;
+/// let cached = CachedCredentialsProvider::new(fake.clone());
+/// assert_eq!(cached.get_credentials().await?.access_key_id, "id-4");
+/// *fake.fail.lock().unwrap() = true;
+/// assert!(cached.get_credentials().await.is_err());
+///
+/// // 不带过期时间的凭证按 refresh_interval 重新获取
+/// let fake = FakeProvider::default();
+/// let cached = CachedCredentialsProvider::new(fake.clone()).refresh_interval(chrono::Duration::zero());
+/// cached.get_credentials().await?;
+/// cached.get_credentials().await?;
+/// assert_eq!(fake.calls.load(Ordering::SeqCst), 2);
+///
+/// // 链式提供者跳过失败的提供者, 缓存后不再重复调用
+/// let failing = FakeProvider::default();
+/// *failing.fail.lock().unwrap() = true;
+/// let fake = FakeProvider::default();
+/// let cached = CachedCredentialsProvider::new(ChainCredentialsProvider::new(vec![]).push(failing.clone()).push(fake.clone()));
+/// assert_eq!(cached.get_credentials().await?.access_key_id, "id-1");
+/// assert_eq!(cached.get_credentials().await?.access_key_id, "id-1");
+/// assert_eq!((failing.calls.load(Ordering::SeqCst), fake.calls.load(Ordering::SeqCst)), (1, 1));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CachedCredentialsProvider {
+	provider: Arc<dyn super::CredentialsProvider>,
+	refresh_before: chrono::Duration,
+	refresh_interval: chrono::Duration,
+	cached: Mutex<Option<(super::Credentials, chrono::DateTime<chrono::Utc>)>>,
+}
+
+impl CachedCredentialsProvider {
+	pub fn new<T: super::CredentialsProvider + 'static>(provider: T) -> Self {
+		Self::new_from_arc(Arc::new(provider))
+	}
+	pub fn new_from_arc(provider: Arc<dyn super::CredentialsProvider>) -> Self {
+		Self {
+			provider,
+			refresh_before: chrono::Duration::minutes(5),
+			refresh_interval: chrono::Duration::hours(1),
+			cached: Mutex::new(None),
+		}
+	}
+	pub fn refresh_before(mut self, duration: chrono::Duration) -> Self {
+		self.refresh_before = duration;
+		self
+	}
+	pub fn refresh_interval(mut self, duration: chrono::Duration) -> Self {
+		self.refresh_interval = duration;
+		self
+	}
+	async fn get_or_refresh(&self) -> anyhow::Result<super::Credentials> {
+		let mut cached = self.cached.lock().await;
+		if let Some((credentials, fetched_at)) = cached.as_ref() {
+			let fresh = match credentials.expiration {
+				Some(_) => !credentials.will_expire_within(self.refresh_before),
+				None => *fetched_at + self.refresh_interval > chrono::Utc::now(),
+			};
+			if fresh {
+				return Ok(credentials.clone());
+			}
+		}
+		match self.provider.get_credentials().await {
+			Ok(credentials) => {
+				*cached = Some((credentials.clone(), chrono::Utc::now()));
+				Ok(credentials)
+			}
+			Err(e) => match cached.as_ref() {
+				Some((credentials, _)) if !credentials.will_expire_within(chrono::Duration::zero()) => Ok(credentials.clone()),
+				_ => Err(e),
+			},
+		}
+	}
+}
+
+impl super::CredentialsProvider for CachedCredentialsProvider {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<super::Credentials>> {
+		self.get_or_refresh().boxed()
+	}
+	fn is_cached(&self) -> bool {
+		true
+	}
+}
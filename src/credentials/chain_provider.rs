@@ -0,0 +1,51 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::sync::Arc;
+
+/// # 按顺序尝试多个凭证提供者
+/// 返回第一个成功获取到的凭证
+#[derive(Debug, Clone)]
+pub struct ChainCredentialsProvider {
+	providers: Vec<Arc<dyn super::CredentialsProvider>>,
+}
+
+impl Default for ChainCredentialsProvider {
+	/// 环境变量 -> OIDC 角色(设置了 RRSA 环境变量时) -> 配置文件 -> ECS 实例 RAM 角色(设置了 ALIBABA_CLOUD_ECS_METADATA 时)
+	fn default() -> Self {
+		let mut providers: Vec<Arc<dyn super::CredentialsProvider>> = vec![Arc::new(super::EnvironmentCredentialsProvider::new())];
+		if let Ok(provider) = super::OidcRoleCredentialsProvider::from_env() {
+			providers.push(Arc::new(provider));
+		}
+		providers.push(Arc::new(super::ProfileCredentialsProvider::default()));
+		if std::env::var("ALIBABA_CLOUD_ECS_METADATA").map(|role_name| !role_name.is_empty()).unwrap_or(false) {
+			providers.push(Arc::new(super::EcsRamRoleCredentialsProvider::default()));
+		}
+		Self::new(providers)
+	}
+}
+
+impl ChainCredentialsProvider {
+	pub fn new(providers: Vec<Arc<dyn super::CredentialsProvider>>) -> Self {
+		Self { providers }
+	}
+	pub fn push<T: super::CredentialsProvider + 'static>(mut self, provider: T) -> Self {
+		self.providers.push(Arc::new(provider));
+		self
+	}
+}
+
+impl super::CredentialsProvider for ChainCredentialsProvider {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<super::Credentials>> {
+		async move {
+			let mut errors = Vec::new();
+			for provider in self.providers.iter() {
+				match provider.get_credentials().await {
+					Ok(credentials) => return Ok(credentials),
+					Err(e) => errors.push(format!("{:?}: {}", provider, e)),
+				}
+			}
+			Err(anyhow::anyhow!("no credentials found in chain: [{}]", errors.join("; ")))
+		}
+		.boxed()
+	}
+}
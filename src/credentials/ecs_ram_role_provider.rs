@@ -0,0 +1,184 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+const ECS_METADATA_URL: &str = "http://100.100.100.200";
+const ECS_METADATA_TOKEN_TTL: u32 = 21600;
+
+/// # 从 ECS 实例元数据服务获取 RAM 角色的临时凭证
+/// https://help.aliyun.com/zh/ecs/user-guide/attach-an-instance-ram-role-to-an-ecs-instance
+///
+/// 默认启用加固模式(IMDSv2): 先获取元数据 token, 再携带 token 读取凭证; token 获取失败时回退到普通模式,
+/// 可以通过 `disable_imdsv1` 或环境变量 ALIBABA_CLOUD_IMDSV1_DISABLED=true 禁止回退
+/// ```
+/// # use ali_oss::{CredentialsProvider, EcsRamRoleCredentialsProvider};
+/// # use std::io::{BufRead, BufReader, Write};
+/// # use std::sync::{Arc, Mutex};
+/// // 本地的元数据服务替身: 按方法与路径返回响应, 记录每个请求的请求行与请求头
+/// fn serve(respond: fn(&str, &str) -> (u16, &'static str)) -> (String, Arc<Mutex<Vec<String>>>) {
+///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+///     let url = format!("http://{}", listener.local_addr().unwrap());
+///     let requests = Arc::new(Mutex::new(Vec::new()));
+///     let log = requests.clone();
+///     std::thread::spawn(move || {
+///         for stream in listener.incoming() {
+///             let mut stream = stream.unwrap();
+///             let mut reader = BufReader::new(stream.try_clone().unwrap());
+///             let mut request = String::new();
+///             while reader.read_line(&mut request).unwrap() > 2 {}
+///             let mut request_line = request.split_whitespace();
+///             let (status, body) = respond(request_line.next().unwrap(), request_line.next().unwrap());
+///             log.lock().unwrap().push(request.to_lowercase());
+///             write!(stream, "HTTP/1.1 {} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+///         }
+///     });
+///     (url, requests)
+/// }
+/// const CREDENTIALS: &str = r#"{"AccessKeyId":"STS.id","AccessKeySecret":"secret","Expiration":"2017-11-01T05:20:01Z","SecurityToken":"token","Code":"Success"}"#;
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// // 加固模式: 先 PUT 获取 token, 之后的请求都带上 token; 未指定角色名时先查询角色名
+/// let (url, log) = serve(|method, path| match (method, path) {
+///     ("PUT", "/latest/api/token") => (200, "metadata-token"),
+///     ("GET", "/latest/meta-data/ram/security-credentials/") => (200, "role-a\n"),
+///     ("GET", "/latest/meta-data/ram/security-credentials/role-a") => (200, CREDENTIALS),
+///     _ => (404, ""),
+/// });
+/// let provider = EcsRamRoleCredentialsProvider::new(None::<String>).metadata_url(&url);
+/// assert_eq!(provider.get_credentials().await?.security_token.as_deref(), Some("token"));
+/// let requests = log.lock().unwrap().clone();
+/// assert_eq!(requests.len(), 3);
+/// assert!(requests[0].starts_with("put /latest/api/token ") && requests[0].contains("x-aliyun-ecs-metadata-token-ttl-seconds: 21600\r\n"));
+/// assert!(requests[1..].iter().all(|request| request.starts_with("get ") && request.contains("x-aliyun-ecs-metadata-token: metadata-token\r\n")));
+///
+/// // token 获取失败时回退到普通模式, 读取凭证时不带 token
+/// let (url, log) = serve(|method, path| match (method, path) {
+///     ("GET", "/latest/meta-data/ram/security-credentials/role-a") => (200, CREDENTIALS),
+///     _ => (403, ""),
+/// });
+/// let provider = EcsRamRoleCredentialsProvider::new(Some("role-a")).metadata_url(&url);
+/// assert_eq!(provider.get_credentials().await?.access_key_id, "STS.id");
+/// let requests = log.lock().unwrap().clone();
+/// assert_eq!(requests.len(), 2);
+/// assert!(requests[1].starts_with("get ") && !requests[1].contains("x-aliyun-ecs-metadata-token:"));
+///
+/// // 禁止回退时 token 获取失败直接返回错误, 不再读取凭证
+/// let provider = provider.disable_imdsv1(true);
+/// let message = provider.get_credentials().await.unwrap_err().to_string();
+/// assert!(message.contains("get ecs metadata token failed"), "{}", message);
+/// let requests = log.lock().unwrap().clone();
+/// assert!(requests.len() == 3 && requests[2].starts_with("put /latest/api/token "));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EcsRamRoleCredentialsProvider {
+	role_name: Option<String>,
+	metadata_url: String,
+	disable_imdsv1: bool,
+	http_client: reqwest::Client,
+}
+
+impl Default for EcsRamRoleCredentialsProvider {
+	/// 角色名取 ALIBABA_CLOUD_ECS_METADATA, 为空时从元数据服务查询
+	fn default() -> Self {
+		let role_name = std::env::var("ALIBABA_CLOUD_ECS_METADATA").ok().filter(|role_name| !role_name.is_empty());
+		let disable_imdsv1 = std::env::var("ALIBABA_CLOUD_IMDSV1_DISABLED").map(|v| v.eq_ignore_ascii_case("true")).unwrap_or(false);
+		let http_client = reqwest::Client::builder()
+			.connect_timeout(std::time::Duration::from_secs(1))
+			.timeout(std::time::Duration::from_secs(5))
+			.no_proxy()
+			.build()
+			.unwrap_or_default();
+		Self {
+			role_name,
+			metadata_url: ECS_METADATA_URL.to_string(),
+			disable_imdsv1,
+			http_client,
+		}
+	}
+}
+
+impl EcsRamRoleCredentialsProvider {
+	pub fn new<T: ToString>(role_name: Option<T>) -> Self {
+		let mut provider = Self::default();
+		if let Some(role_name) = role_name {
+			provider.role_name = Some(role_name.to_string());
+		}
+		provider
+	}
+	/// 元数据服务地址, 默认为 http://100.100.100.200
+	pub fn metadata_url<T: ToString>(mut self, metadata_url: T) -> Self {
+		self.metadata_url = metadata_url.to_string().trim_end_matches('/').to_string();
+		self
+	}
+	pub fn disable_imdsv1(mut self, disable_imdsv1: bool) -> Self {
+		self.disable_imdsv1 = disable_imdsv1;
+		self
+	}
+	pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+		self.http_client = http_client;
+		self
+	}
+
+	async fn get_metadata_token(&self) -> anyhow::Result<Option<String>> {
+		let url = format!("{}/latest/api/token", self.metadata_url);
+		let result = self.http_client.put(url).header("X-aliyun-ecs-metadata-token-ttl-seconds", ECS_METADATA_TOKEN_TTL.to_string()).send().await;
+		match result {
+			Ok(response) if response.status().is_success() => Ok(Some(response.text().await?)),
+			Ok(response) if self.disable_imdsv1 => Err(anyhow::anyhow!("get ecs metadata token failed: {}", response.status())),
+			Err(e) if self.disable_imdsv1 => Err(anyhow::anyhow!("get ecs metadata token failed: {}", e)),
+			_ => Ok(None),
+		}
+	}
+	async fn get_metadata(&self, path: &str, token: Option<&str>) -> anyhow::Result<String> {
+		let url = format!("{}/latest/meta-data/ram/security-credentials/{}", self.metadata_url, path);
+		let mut request = self.http_client.get(url);
+		if let Some(token) = token {
+			request = request.header("X-aliyun-ecs-metadata-token", token);
+		}
+		let response = request.send().await?;
+		if !response.status().is_success() {
+			return Err(anyhow::anyhow!("get ecs metadata {} failed: {}", path, response.status()));
+		}
+		Ok(response.text().await?)
+	}
+	async fn fetch(&self) -> anyhow::Result<super::Credentials> {
+		let token = self.get_metadata_token().await?;
+		let role_name = match &self.role_name {
+			Some(role_name) => role_name.clone(),
+			None => {
+				let role_names = self.get_metadata("", token.as_deref()).await?;
+				role_names.lines().next().map(|line| line.trim().to_string()).filter(|line| !line.is_empty()).ok_or_else(|| anyhow::anyhow!("no ram role attached to the ecs instance"))?
+			}
+		};
+		let content = self.get_metadata(&role_name, token.as_deref()).await?;
+		Self::parse_credentials(&content)
+	}
+	/// # 解析元数据服务返回的凭证
+	/// ```
+	/// # use ali_oss::EcsRamRoleCredentialsProvider;
+	/// let content = r#"{"AccessKeyId":"STS.id","AccessKeySecret":"secret","Expiration":"2017-11-01T05:20:01Z","SecurityToken":"token","LastUpdated":"2017-10-31T23:20:01Z","Code":"Success"}"#;
+	/// let credentials = EcsRamRoleCredentialsProvider::parse_credentials(content).unwrap();
+	/// assert_eq!(credentials.security_token.as_deref(), Some("token"));
+	/// assert_eq!(credentials.expiration.unwrap().to_rfc3339(), "2017-11-01T05:20:01+00:00");
+	/// ```
+	pub fn parse_credentials(content: &str) -> anyhow::Result<super::Credentials> {
+		let value: serde_json::Value = serde_json::from_str(content)?;
+		if let Some(code) = value["Code"].as_str() {
+			if code != "Success" {
+				return Err(anyhow::anyhow!("get ecs ram role credentials failed: {}", code));
+			}
+		}
+		let access_key_id = value["AccessKeyId"].as_str().ok_or_else(|| anyhow::anyhow!("AccessKeyId not found"))?;
+		let access_key_secret = value["AccessKeySecret"].as_str().ok_or_else(|| anyhow::anyhow!("AccessKeySecret not found"))?;
+		let security_token = value["SecurityToken"].as_str().ok_or_else(|| anyhow::anyhow!("SecurityToken not found"))?;
+		let expiration = value["Expiration"].as_str().ok_or_else(|| anyhow::anyhow!("Expiration not found"))?;
+		Ok(super::Credentials::new(access_key_id, access_key_secret, Some(security_token)).with_expiration(expiration.parse()?))
+	}
+}
+
+impl super::CredentialsProvider for EcsRamRoleCredentialsProvider {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<super::Credentials>> {
+		self.fetch().boxed()
+	}
+}
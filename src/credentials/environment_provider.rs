@@ -0,0 +1,27 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+/// # 从环境变量读取访问凭证
+/// 依次读取 ALI_OSS_ACCESS_KEY_ID / ALI_OSS_ACCESS_KEY_SECRET / ALI_OSS_SECURITY_TOKEN,
+/// 以及阿里云通用的 ALIBABA_CLOUD_ACCESS_KEY_ID / ALIBABA_CLOUD_ACCESS_KEY_SECRET / ALIBABA_CLOUD_SECURITY_TOKEN
+#[derive(Debug, Clone, Default)]
+pub struct EnvironmentCredentialsProvider;
+
+impl EnvironmentCredentialsProvider {
+	pub fn new() -> Self {
+		Self
+	}
+	fn read(&self) -> anyhow::Result<super::Credentials> {
+		let var = |names: &[&str]| names.iter().find_map(|name| std::env::var(name).ok().filter(|v| !v.is_empty()));
+		let access_key_id = var(&["ALI_OSS_ACCESS_KEY_ID", "ALIBABA_CLOUD_ACCESS_KEY_ID"]).ok_or_else(|| anyhow::anyhow!("access key id not found in environment"))?;
+		let access_key_secret = var(&["ALI_OSS_ACCESS_KEY_SECRET", "ALIBABA_CLOUD_ACCESS_KEY_SECRET"]).ok_or_else(|| anyhow::anyhow!("access key secret not found in environment"))?;
+		let security_token = var(&["ALI_OSS_SECURITY_TOKEN", "ALIBABA_CLOUD_SECURITY_TOKEN"]);
+		Ok(super::Credentials::new(access_key_id, access_key_secret, security_token))
+	}
+}
+
+impl super::CredentialsProvider for EnvironmentCredentialsProvider {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<super::Credentials>> {
+		futures::future::ready(self.read()).boxed()
+	}
+}
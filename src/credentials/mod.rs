@@ -0,0 +1,89 @@
+mod assume_role_provider;
+mod cached_provider;
+mod chain_provider;
+mod ecs_ram_role_provider;
+mod environment_provider;
+mod oidc_role_provider;
+mod profile_provider;
+mod static_provider;
+pub mod sts;
+
+pub use assume_role_provider::AssumeRoleCredentialsProvider;
+pub use cached_provider::CachedCredentialsProvider;
+pub use chain_provider::ChainCredentialsProvider;
+pub use ecs_ram_role_provider::EcsRamRoleCredentialsProvider;
+pub use environment_provider::EnvironmentCredentialsProvider;
+pub use oidc_role_provider::OidcRoleCredentialsProvider;
+pub use profile_provider::ProfileCredentialsProvider;
+pub use static_provider::StaticCredentialsProvider;
+
+use futures::future::BoxFuture;
+
+/// # 访问凭证
+/// 临时凭证(STS)带有 security_token 与过期时间
+#[derive(Clone)]
+pub struct Credentials {
+	pub access_key_id: String,
+	pub access_key_secret: String,
+	pub security_token: Option<String>,
+	pub expiration: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl std::fmt::Debug for Credentials {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Credentials")
+			.field("access_key_id", &self.access_key_id)
+			.field("access_key_secret", &"******")
+			.field("security_token", &self.security_token.as_ref().map(|_| "******"))
+			.field("expiration", &self.expiration)
+			.finish()
+	}
+}
+
+impl Credentials {
+	pub fn new<T: ToString>(access_key_id: T, access_key_secret: T, security_token: Option<T>) -> Self {
+		Self {
+			access_key_id: access_key_id.to_string(),
+			access_key_secret: access_key_secret.to_string(),
+			security_token: security_token.map(|token| token.to_string()),
+			expiration: None,
+		}
+	}
+	pub fn with_expiration(mut self, expiration: chrono::DateTime<chrono::Utc>) -> Self {
+		self.expiration = Some(expiration);
+		self
+	}
+	/// # 凭证是否会在 duration 内过期
+	/// ```
+	/// # use ali_oss::Credentials;
+	/// let credentials = Credentials::new("id", "secret", None);
+	/// assert!(!credentials.will_expire_within(chrono::Duration::minutes(5)));
+	/// let credentials = credentials.with_expiration(chrono::Utc::now() + chrono::Duration::minutes(3));
+	/// assert!(credentials.will_expire_within(chrono::Duration::minutes(5)));
+	/// ```
+	pub fn will_expire_within(&self, duration: chrono::Duration) -> bool {
+		match self.expiration {
+			Some(expiration) => expiration - duration <= chrono::Utc::now(),
+			None => false,
+		}
+	}
+}
+
+/// # 凭证提供者
+/// 每次发送请求前都会调用 `get_credentials` 获取用于签名的凭证
+pub trait CredentialsProvider: std::fmt::Debug + Send + Sync {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<Credentials>>;
+	/// 是否已经自行缓存凭证, 为 true 时 `ClientBuilder` 不会再包装一层 `CachedCredentialsProvider`
+	fn is_cached(&self) -> bool {
+		false
+	}
+}
+
+impl<T: CredentialsProvider + ?Sized> CredentialsProvider for std::sync::Arc<T> {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<Credentials>> {
+		(**self).get_credentials()
+	}
+	fn is_cached(&self) -> bool {
+		(**self).is_cached()
+	}
+}
@@ -0,0 +1,169 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::path::PathBuf;
+
+/// # 通过 STS AssumeRoleWithOIDC 获取临时凭证(RRSA)
+/// https://help.aliyun.com/zh/ram/developer-reference/api-sts-2015-04-01-assumerolewithoidc
+///
+/// 每次获取凭证时都会重新读取 OIDC token 文件, 以便感知 token 轮换
+/// ```
+/// # use ali_oss::{CredentialsProvider, OidcRoleCredentialsProvider};
+/// # use std::collections::BTreeMap;
+/// # use std::io::{BufRead, BufReader, Read, Write};
+/// # use std::sync::{Arc, Mutex};
+/// // 本地的 STS 替身: 按表单内容返回响应, 记录每个请求的请求行与表单
+/// fn serve(respond: fn(&str) -> (u16, &'static str)) -> (String, Arc<Mutex<Vec<String>>>) {
+///     let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+///     let url = format!("http://{}", listener.local_addr().unwrap());
+///     let requests = Arc::new(Mutex::new(Vec::new()));
+///     let log = requests.clone();
+///     std::thread::spawn(move || {
+///         for stream in listener.incoming() {
+///             let mut stream = stream.unwrap();
+///             let mut reader = BufReader::new(stream.try_clone().unwrap());
+///             let (mut request, mut content_length) = (String::new(), 0);
+///             loop {
+///                 let mut line = String::new();
+///                 reader.read_line(&mut line).unwrap();
+///                 if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+///                     content_length = value.trim().parse().unwrap();
+///                 }
+///                 if line == "\r\n" {
+///                     break;
+///                 }
+///                 request.push_str(&line);
+///             }
+///             let mut form = vec![0u8; content_length];
+///             reader.read_exact(&mut form).unwrap();
+///             let form = String::from_utf8(form).unwrap();
+///             let (status, body) = respond(&form);
+///             log.lock().unwrap().push(format!("{}{}", request.lines().next().unwrap(), form));
+///             write!(stream, "HTTP/1.1 {} OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+///         }
+///     });
+///     (url, requests)
+/// }
+/// fn parse_form(request: &str) -> BTreeMap<String, String> {
+///     let form = request.split_once("HTTP/1.1").unwrap().1;
+///     reqwest::Url::parse(&format!("http://localhost/?{}", form)).unwrap().query_pairs().into_owned().collect()
+/// }
+/// const CREDENTIALS: &str = r#"{"RequestId":"1","Credentials":{"SecurityToken":"token","AccessKeyId":"STS.id","AccessKeySecret":"secret","Expiration":"2015-04-09T11:52:19Z"}}"#;
+/// const NO_PERMISSION: &str = r#"{"RequestId":"2","Code":"NoPermission","Message":"You are not authorized to do this action."}"#;
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let (url, log) = serve(|form| if form.contains("OIDCToken=expired") { (400, NO_PERMISSION) } else { (200, CREDENTIALS) });
+/// let token_file = std::env::temp_dir().join(format!("ali-oss-oidc-token-{}", std::process::id()));
+/// std::fs::write(&token_file, "token-1\n")?;
+/// let provider = OidcRoleCredentialsProvider::new("acs:ram::1:role/reader", "acs:ram::1:oidc-provider/ack", &token_file).role_session_name("session").sts_endpoint(&url);
+/// assert_eq!(provider.get_credentials().await?.access_key_id, "STS.id");
+///
+/// // 匿名接口, 表单带有从文件读取的 token, 没有签名
+/// let form = parse_form(&log.lock().unwrap()[0]);
+/// assert_eq!(form["Action"], "AssumeRoleWithOIDC");
+/// assert_eq!((form["RoleArn"].as_str(), form["OIDCProviderArn"].as_str(), form["RoleSessionName"].as_str()), ("acs:ram::1:role/reader", "acs:ram::1:oidc-provider/ack", "session"));
+/// assert_eq!(form["OIDCToken"], "token-1");
+/// assert!(!form.contains_key("Signature") && !form.contains_key("AccessKeyId"));
+///
+/// // 每次都重新读取 token 文件
+/// std::fs::write(&token_file, "token-2")?;
+/// provider.get_credentials().await?;
+/// assert_eq!(parse_form(&log.lock().unwrap()[1])["OIDCToken"], "token-2");
+///
+/// // STS 返回的错误
+/// std::fs::write(&token_file, "expired")?;
+/// let message = provider.get_credentials().await.unwrap_err().to_string();
+/// assert!(message.contains("400") && message.contains("NoPermission"), "{}", message);
+///
+/// // token 文件不存在
+/// std::fs::remove_file(&token_file)?;
+/// let message = provider.get_credentials().await.unwrap_err().to_string();
+/// assert!(message.contains("read oidc token file"), "{}", message);
+/// assert_eq!(log.lock().unwrap().len(), 3);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OidcRoleCredentialsProvider {
+	role_arn: String,
+	oidc_provider_arn: String,
+	oidc_token_file: PathBuf,
+	role_session_name: String,
+	duration_seconds: u64,
+	policy: Option<String>,
+	sts_endpoint: String,
+	http_client: reqwest::Client,
+}
+
+impl OidcRoleCredentialsProvider {
+	pub fn new<T: ToString, P: Into<PathBuf>>(role_arn: T, oidc_provider_arn: T, oidc_token_file: P) -> Self {
+		Self {
+			role_arn: role_arn.to_string(),
+			oidc_provider_arn: oidc_provider_arn.to_string(),
+			oidc_token_file: oidc_token_file.into(),
+			role_session_name: format!("ali-oss-{}", chrono::Utc::now().timestamp()),
+			duration_seconds: 3600,
+			policy: None,
+			sts_endpoint: super::sts::STS_ENDPOINT.to_string(),
+			http_client: reqwest::Client::new(),
+		}
+	}
+	/// # 读取 RRSA 注入的环境变量
+	/// ALIBABA_CLOUD_ROLE_ARN, ALIBABA_CLOUD_OIDC_PROVIDER_ARN, ALIBABA_CLOUD_OIDC_TOKEN_FILE,
+	/// 以及可选的 ALIBABA_CLOUD_ROLE_SESSION_NAME, ALIBABA_CLOUD_STS_ENDPOINT
+	pub fn from_env() -> anyhow::Result<Self> {
+		let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+		let role_arn = var("ALIBABA_CLOUD_ROLE_ARN").ok_or_else(|| anyhow::anyhow!("ALIBABA_CLOUD_ROLE_ARN not found"))?;
+		let oidc_provider_arn = var("ALIBABA_CLOUD_OIDC_PROVIDER_ARN").ok_or_else(|| anyhow::anyhow!("ALIBABA_CLOUD_OIDC_PROVIDER_ARN not found"))?;
+		let oidc_token_file = var("ALIBABA_CLOUD_OIDC_TOKEN_FILE").ok_or_else(|| anyhow::anyhow!("ALIBABA_CLOUD_OIDC_TOKEN_FILE not found"))?;
+		let mut provider = Self::new(role_arn, oidc_provider_arn, oidc_token_file);
+		if let Some(role_session_name) = var("ALIBABA_CLOUD_ROLE_SESSION_NAME") {
+			provider.role_session_name = role_session_name;
+		}
+		if let Some(sts_endpoint) = var("ALIBABA_CLOUD_STS_ENDPOINT") {
+			provider.sts_endpoint = if sts_endpoint.contains("://") { sts_endpoint } else { format!("https://{}", sts_endpoint) };
+		}
+		Ok(provider)
+	}
+	pub fn role_session_name<T: ToString>(mut self, role_session_name: T) -> Self {
+		self.role_session_name = role_session_name.to_string();
+		self
+	}
+	pub fn duration_seconds(mut self, duration_seconds: u64) -> Self {
+		self.duration_seconds = duration_seconds;
+		self
+	}
+	pub fn policy<T: ToString>(mut self, policy: T) -> Self {
+		self.policy = Some(policy.to_string());
+		self
+	}
+	/// STS 地址, 默认为 https://sts.aliyuncs.com
+	pub fn sts_endpoint<T: ToString>(mut self, sts_endpoint: T) -> Self {
+		self.sts_endpoint = sts_endpoint.to_string();
+		self
+	}
+	pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+		self.http_client = http_client;
+		self
+	}
+
+	async fn fetch(&self) -> anyhow::Result<super::Credentials> {
+		let oidc_token = tokio::fs::read_to_string(&self.oidc_token_file).await.map_err(|e| anyhow::anyhow!("read oidc token file {:?} failed: {}", self.oidc_token_file, e))?;
+		let mut params = super::sts::common_params("AssumeRoleWithOIDC")?;
+		params.insert("RoleArn".to_string(), self.role_arn.clone());
+		params.insert("OIDCProviderArn".to_string(), self.oidc_provider_arn.clone());
+		params.insert("OIDCToken".to_string(), oidc_token.trim().to_string());
+		params.insert("RoleSessionName".to_string(), self.role_session_name.clone());
+		params.insert("DurationSeconds".to_string(), self.duration_seconds.to_string());
+		if let Some(policy) = &self.policy {
+			params.insert("Policy".to_string(), policy.clone());
+		}
+		// AssumeRoleWithOIDC 为匿名接口, 无需签名
+		super::sts::request_credentials(&self.http_client, &self.sts_endpoint, &params).await
+	}
+}
+
+impl super::CredentialsProvider for OidcRoleCredentialsProvider {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<super::Credentials>> {
+		self.fetch().boxed()
+	}
+}
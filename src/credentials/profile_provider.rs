@@ -0,0 +1,108 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::path::PathBuf;
+
+/// # 从配置文件读取访问凭证
+/// 支持 INI 格式(~/.alibabacloud/credentials) 与 aliyun cli 的 JSON 格式(~/.aliyun/config.json)
+/// ```
+/// # use ali_oss::{CredentialsProvider, ProfileCredentialsProvider};
+/// # #[tokio::main]
+/// # async fn main() {
+/// let path = std::env::temp_dir().join("ali_oss_profile_doctest");
+/// std::fs::write(&path, "[default]\ntype = access_key\naccess_key_id = id\naccess_key_secret = secret\n").unwrap();
+/// let credentials = ProfileCredentialsProvider::new(&path, None).get_credentials().await.unwrap();
+/// assert_eq!(credentials.access_key_id, "id");
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProfileCredentialsProvider {
+	path: PathBuf,
+	profile: Option<String>,
+}
+
+impl Default for ProfileCredentialsProvider {
+	/// 文件路径取 ALIBABA_CLOUD_CREDENTIALS_FILE, 默认为 ~/.alibabacloud/credentials;
+	/// 配置名取 ALIBABA_CLOUD_PROFILE
+	fn default() -> Self {
+		let path = match std::env::var("ALIBABA_CLOUD_CREDENTIALS_FILE") {
+			Ok(path) if !path.is_empty() => PathBuf::from(path),
+			_ => {
+				let home = std::env::var("HOME").or_else(|_| std::env::var("USERPROFILE")).unwrap_or_default();
+				PathBuf::from(home).join(".alibabacloud").join("credentials")
+			}
+		};
+		let profile = std::env::var("ALIBABA_CLOUD_PROFILE").ok().filter(|profile| !profile.is_empty());
+		Self { path, profile }
+	}
+}
+
+impl ProfileCredentialsProvider {
+	/// profile 为空时, INI 文件使用 default, JSON 文件使用 current 指定的配置
+	pub fn new<P: Into<PathBuf>>(path: P, profile: Option<&str>) -> Self {
+		Self {
+			path: path.into(),
+			profile: profile.map(|profile| profile.to_string()),
+		}
+	}
+	async fn read(&self) -> anyhow::Result<super::Credentials> {
+		let content = tokio::fs::read_to_string(&self.path).await.map_err(|e| anyhow::anyhow!("read credentials file {:?} failed: {}", self.path, e))?;
+		if content.trim_start().starts_with('{') {
+			self.parse_json(&content)
+		} else {
+			self.parse_ini(&content)
+		}
+	}
+	fn parse_ini(&self, content: &str) -> anyhow::Result<super::Credentials> {
+		let profile = self.profile.as_deref().unwrap_or("default");
+		let mut section = None;
+		let mut values = std::collections::HashMap::new();
+		for line in content.lines() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+				continue;
+			}
+			if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+				section = Some(name.trim().to_string());
+				continue;
+			}
+			if section.as_deref() != Some(profile) {
+				continue;
+			}
+			if let Some((k, v)) = line.split_once('=') {
+				values.insert(k.trim().to_string(), v.trim().trim_matches('"').to_string());
+			}
+		}
+		if values.is_empty() {
+			return Err(anyhow::anyhow!("profile {} not found in {:?}", profile, self.path));
+		}
+		let access_key_id = values.remove("access_key_id").ok_or_else(|| anyhow::anyhow!("access_key_id not found in profile {}", profile))?;
+		let access_key_secret = values.remove("access_key_secret").ok_or_else(|| anyhow::anyhow!("access_key_secret not found in profile {}", profile))?;
+		let security_token = values.remove("security_token").or_else(|| values.remove("sts_token")).filter(|token| !token.is_empty());
+		Ok(super::Credentials::new(access_key_id, access_key_secret, security_token))
+	}
+	fn parse_json(&self, content: &str) -> anyhow::Result<super::Credentials> {
+		let config: serde_json::Value = serde_json::from_str(content)?;
+		let profile = match &self.profile {
+			Some(profile) => profile.as_str(),
+			None => config["current"].as_str().unwrap_or("default"),
+		};
+		let node = config["profiles"]
+			.as_array()
+			.and_then(|profiles| profiles.iter().find(|node| node["name"].as_str() == Some(profile)))
+			.ok_or_else(|| anyhow::anyhow!("profile {} not found in {:?}", profile, self.path))?;
+		let access_key_id = node["access_key_id"].as_str().filter(|v| !v.is_empty()).ok_or_else(|| anyhow::anyhow!("access_key_id not found in profile {}", profile))?;
+		let access_key_secret = node["access_key_secret"].as_str().filter(|v| !v.is_empty()).ok_or_else(|| anyhow::anyhow!("access_key_secret not found in profile {}", profile))?;
+		let security_token = node["sts_token"].as_str().filter(|v| !v.is_empty());
+		let mut credentials = super::Credentials::new(access_key_id, access_key_secret, security_token);
+		if let Some(expiration) = node["sts_expiration"].as_i64().filter(|v| *v > 0) {
+			credentials.expiration = chrono::DateTime::from_timestamp(expiration, 0);
+		}
+		Ok(credentials)
+	}
+}
+
+impl super::CredentialsProvider for ProfileCredentialsProvider {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<super::Credentials>> {
+		self.read().boxed()
+	}
+}
@@ -0,0 +1,29 @@
+use futures::future::BoxFuture;
+use futures::FutureExt;
+
+/// # 固定的访问凭证
+#[derive(Debug, Clone)]
+pub struct StaticCredentialsProvider {
+	credentials: super::Credentials,
+}
+
+impl StaticCredentialsProvider {
+	pub fn new<T: ToString>(access_key_id: T, access_key_secret: T, security_token: Option<T>) -> Self {
+		Self {
+			credentials: super::Credentials::new(access_key_id, access_key_secret, security_token),
+		}
+	}
+	pub fn new_from_credentials(credentials: super::Credentials) -> Self {
+		Self { credentials }
+	}
+}
+
+impl super::CredentialsProvider for StaticCredentialsProvider {
+	fn get_credentials(&self) -> BoxFuture<'_, anyhow::Result<super::Credentials>> {
+		futures::future::ready(Ok(self.credentials.clone())).boxed()
+	}
+	/// 固定的凭证无需缓存
+	fn is_cached(&self) -> bool {
+		true
+	}
+}
@@ -0,0 +1,95 @@
+//! # STS 接口的签名与响应解析
+
+use base64::prelude::*;
+use ring::rand::SecureRandom;
+use std::collections::BTreeMap;
+
+pub(crate) const STS_ENDPOINT: &str = "https://sts.aliyuncs.com";
+const STS_VERSION: &str = "2015-04-01";
+
+/// STS 接口的公共参数
+pub(crate) fn common_params(action: &str) -> anyhow::Result<BTreeMap<String, String>> {
+	let mut nonce = [0u8; 16];
+	ring::rand::SystemRandom::new().fill(&mut nonce).map_err(|_| anyhow::anyhow!("generate signature nonce failed"))?;
+	let mut params = BTreeMap::new();
+	params.insert("Action".to_string(), action.to_string());
+	params.insert("Format".to_string(), "JSON".to_string());
+	params.insert("Version".to_string(), STS_VERSION.to_string());
+	params.insert("Timestamp".to_string(), chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string());
+	params.insert("SignatureNonce".to_string(), crate::types::signature::hex_encode(&nonce));
+	Ok(params)
+}
+
+/// # RPC 风格接口签名
+/// https://help.aliyun.com/zh/sdk/product-overview/rpc-mechanism
+/// ```
+/// # use ali_oss::Credentials;
+/// # use std::collections::BTreeMap;
+/// // 文档中的签名示例
+/// let mut params = BTreeMap::new();
+/// params.insert("Action".to_string(), "DescribeRegions".to_string());
+/// params.insert("Format".to_string(), "XML".to_string());
+/// params.insert("SignatureNonce".to_string(), "3ee8c1b8-83d3-44af-a94f-4e0ad82fd6cf".to_string());
+/// params.insert("Timestamp".to_string(), "2016-02-23T12:46:24Z".to_string());
+/// params.insert("Version".to_string(), "2014-05-26".to_string());
+/// ali_oss::sts::sign_params(&reqwest::Method::GET, &mut params, &Credentials::new("testid", "testsecret", None));
+/// assert_eq!(params["Signature"], "OLeaidS1JvxuMvnyHOwuJ+uX5qY=");
+/// ```
+pub fn sign_params(method: &reqwest::Method, params: &mut BTreeMap<String, String>, credentials: &super::Credentials) {
+	params.insert("AccessKeyId".to_string(), credentials.access_key_id.clone());
+	params.insert("SignatureMethod".to_string(), "HMAC-SHA1".to_string());
+	params.insert("SignatureVersion".to_string(), "1.0".to_string());
+	if let Some(security_token) = &credentials.security_token {
+		params.insert("SecurityToken".to_string(), security_token.clone());
+	}
+	params.remove("Signature");
+	let canonicalized_query = params
+		.iter()
+		.map(|(k, v)| format!("{}={}", crate::types::signature::uri_encode(k, true), crate::types::signature::uri_encode(v, true)))
+		.collect::<Vec<String>>()
+		.join("&");
+	let string_to_sign = format!("{}&{}&{}", method.as_str(), crate::types::signature::uri_encode("/", true), crate::types::signature::uri_encode(&canonicalized_query, true));
+	let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, format!("{}&", credentials.access_key_secret).as_bytes());
+	let signature = BASE64_STANDARD.encode(ring::hmac::sign(&key, string_to_sign.as_bytes()).as_ref());
+	params.insert("Signature".to_string(), signature);
+}
+
+/// 以表单方式 POST 到 STS 接口并解析返回的临时凭证
+pub(crate) async fn request_credentials(http_client: &reqwest::Client, endpoint: &str, params: &BTreeMap<String, String>) -> anyhow::Result<super::Credentials> {
+	let response = http_client.post(endpoint).form(params).send().await?;
+	let status = response.status();
+	let content = response.text().await?;
+	parse_credentials(status, &content)
+}
+
+/// # 解析 STS 接口的响应
+/// ```
+/// # use ali_oss::sts::parse_credentials;
+/// let content = r#"{"RequestId":"6894B13B-6D71-4EF5-88FA-F32781734A7F","AssumedRoleUser":{"AssumedRoleId":"34458433936495****:alice","Arn":"acs:ram::123456789012****:role/alice"},"Credentials":{"SecurityToken":"CAES+wMIARKAAZhjH0EUOIhJMQBMjRywXq7MQ/cjLYg80Aho1ek0Jm63XMhr9Oc5s****","AccessKeyId":"STS.L4aBSCSJVMuKg5U1****","AccessKeySecret":"wyLTSmsyPGP1ohvvw8xYgB29dlGI8KMiH2pK****","Expiration":"2015-04-09T11:52:19Z"}}"#;
+/// let credentials = parse_credentials(reqwest::StatusCode::OK, content).unwrap();
+/// assert_eq!(credentials.access_key_id, "STS.L4aBSCSJVMuKg5U1****");
+/// assert_eq!(credentials.security_token.as_deref(), Some("CAES+wMIARKAAZhjH0EUOIhJMQBMjRywXq7MQ/cjLYg80Aho1ek0Jm63XMhr9Oc5s****"));
+/// assert_eq!(credentials.expiration, Some("2015-04-09T11:52:19Z".parse().unwrap()));
+///
+/// let error = r#"{"RequestId":"7708B15A-B4A9-4B06-A6C6-2A5AE1D4F2A1","Code":"NoPermission","Message":"You are not authorized to do this action."}"#;
+/// let message = parse_credentials(reqwest::StatusCode::FORBIDDEN, error).unwrap_err().to_string();
+/// assert!(message.contains("NoPermission"));
+/// ```
+pub fn parse_credentials(status: reqwest::StatusCode, content: &str) -> anyhow::Result<super::Credentials> {
+	let value: serde_json::Value = serde_json::from_str(content).map_err(|e| anyhow::anyhow!("parse sts response failed ({}): {}, {}", status, e, content))?;
+	if !status.is_success() {
+		return Err(anyhow::anyhow!(
+			"sts request failed ({}): {}: {}, request id: {}",
+			status,
+			value["Code"].as_str().unwrap_or(""),
+			value["Message"].as_str().unwrap_or(""),
+			value["RequestId"].as_str().unwrap_or("")
+		));
+	}
+	let node = &value["Credentials"];
+	let access_key_id = node["AccessKeyId"].as_str().ok_or_else(|| anyhow::anyhow!("AccessKeyId not found"))?;
+	let access_key_secret = node["AccessKeySecret"].as_str().ok_or_else(|| anyhow::anyhow!("AccessKeySecret not found"))?;
+	let security_token = node["SecurityToken"].as_str().ok_or_else(|| anyhow::anyhow!("SecurityToken not found"))?;
+	let expiration = node["Expiration"].as_str().ok_or_else(|| anyhow::anyhow!("Expiration not found"))?;
+	Ok(super::Credentials::new(access_key_id, access_key_secret, Some(security_token)).with_expiration(expiration.parse()?))
+}
@@ -0,0 +1,193 @@
+use crate::transfer::{is_fatal_error, with_suffix};
+use crate::Client;
+use futures::StreamExt;
+use std::path::Path;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
+
+/// # 分片下载的断点记录
+/// 对象的 ETag 或大小变化后断点失效, 重新下载
+#[derive(Debug, Clone)]
+struct DownloadCheckpoint {
+	bucket: String,
+	key: String,
+	etag: String,
+	object_size: u64,
+	part_size: u64,
+	/// 已完成的分片序号(从 0 开始)及其 CRC64
+	parts: Vec<(u64, u64)>,
+}
+
+impl DownloadCheckpoint {
+	async fn load(checkpoint_file: &Path) -> Option<Self> {
+		let json_data = tokio::fs::read(checkpoint_file).await.ok()?;
+		let value: serde_json::Value = serde_json::from_slice(&json_data).ok()?;
+		let mut parts = Vec::new();
+		for part in value["parts"].as_array()? {
+			parts.push((part["index"].as_u64()?, part["crc64"].as_str()?.parse().ok()?));
+		}
+		Some(Self {
+			bucket: value["bucket"].as_str()?.to_string(),
+			key: value["key"].as_str()?.to_string(),
+			etag: value["etag"].as_str()?.to_string(),
+			object_size: value["object_size"].as_u64()?,
+			part_size: value["part_size"].as_u64()?,
+			parts,
+		})
+	}
+	/// 先写临时文件再重命名, 避免进程中断时留下不完整的断点文件
+	async fn save(&self, checkpoint_file: &Path) -> anyhow::Result<()> {
+		let parts = self.parts.iter().map(|(index, crc64)| serde_json::json!({ "index": index, "crc64": crc64.to_string() })).collect::<Vec<_>>();
+		let value = serde_json::json!({
+			"bucket": self.bucket,
+			"key": self.key,
+			"etag": self.etag,
+			"object_size": self.object_size,
+			"part_size": self.part_size,
+			"parts": parts,
+		});
+		let tmp_file = with_suffix(checkpoint_file, ".tmp");
+		tokio::fs::write(&tmp_file, serde_json::to_vec(&value)?).await?;
+		tokio::fs::rename(&tmp_file, checkpoint_file).await?;
+		Ok(())
+	}
+	fn is_valid(&self, bucket: &str, key: &str, etag: &str, object_size: u64, part_size: u64) -> bool {
+		self.bucket == bucket && self.key == key && self.etag == etag && self.object_size == object_size && self.part_size == part_size
+	}
+}
+
+impl Client {
+	/// # 分片并发下载对象到本地文件
+	/// 数据先写入 `{path}.tmp`, 校验大小与 CRC64 后重命名为 path.
+	/// 每个 Range 请求都带有 `If-Match: {etag}`, 下载过程中对象被修改会返回 PreconditionFailed 并清除断点
+	pub async fn download_file<P: AsRef<Path>>(&self, object_name: &str, path: P, options: crate::DownloadFileOptions) -> anyhow::Result<crate::ObjectMeta> {
+		let path = path.as_ref();
+		let meta = self.head_object(object_name, None).await?;
+		let object_size = meta.content_length;
+		let etag = meta.etag.clone();
+		let remote_crc64 = meta.crc64;
+		let part_size = options.part_size.max(1);
+		let bucket = self.oss_config().bucket_name.clone();
+		let key = self.oss_config().get_object_name(object_name).into_owned();
+		let temp_file = with_suffix(path, ".tmp");
+		let checkpoint_file = match &options.checkpoint_file {
+			Some(checkpoint_file) => checkpoint_file.clone(),
+			None => with_suffix(path, ".dcp"),
+		};
+
+		let mut checkpoint = None;
+		if options.enable_checkpoint {
+			if let Some(saved) = DownloadCheckpoint::load(&checkpoint_file).await {
+				let temp_size = tokio::fs::metadata(&temp_file).await.map(|metadata| metadata.len()).ok();
+				if saved.is_valid(&bucket, &key, &etag, object_size, part_size) && temp_size == Some(object_size) {
+					checkpoint = Some(saved);
+				}
+			}
+		}
+		let mut checkpoint = match checkpoint {
+			Some(checkpoint) => checkpoint,
+			None => {
+				let file = tokio::fs::File::create(&temp_file).await?;
+				file.set_len(object_size).await?;
+				DownloadCheckpoint {
+					bucket,
+					key,
+					etag: etag.clone(),
+					object_size,
+					part_size,
+					parts: Vec::new(),
+				}
+			}
+		};
+		if options.enable_checkpoint {
+			checkpoint.save(&checkpoint_file).await?;
+		}
+
+		let part_count = object_size.div_ceil(part_size);
+		let pending_parts = (0..part_count).filter(|index| !checkpoint.parts.iter().any(|(done, _)| done == index)).collect::<Vec<_>>();
+		let mut downloads = futures::stream::iter(pending_parts)
+			.map(|index| {
+				let start = index * part_size;
+				let end = (start + part_size).min(object_size) - 1;
+				let temp_file = &temp_file;
+				let etag = &etag;
+				async move { self.download_file_part(object_name, temp_file, etag, start, end).await.map(|crc64| (index, crc64)) }
+			})
+			.buffer_unordered(options.parallel.max(1));
+		let mut download_error = None;
+		while let Some(result) = downloads.next().await {
+			match result {
+				Ok(part) => {
+					checkpoint.parts.push(part);
+					if options.enable_checkpoint {
+						checkpoint.save(&checkpoint_file).await?;
+					}
+				}
+				Err(e) => {
+					download_error = Some(e);
+					break;
+				}
+			}
+		}
+		drop(downloads);
+		if let Some(e) = download_error {
+			if is_fatal_error(&e) {
+				let _ = tokio::fs::remove_file(&temp_file).await;
+				let _ = tokio::fs::remove_file(&checkpoint_file).await;
+			}
+			return Err(e);
+		}
+
+		let temp_size = tokio::fs::metadata(&temp_file).await?.len();
+		checkpoint.parts.sort_by_key(|(index, _)| *index);
+		let local_crc64 = checkpoint.parts.iter().fold(0u64, |crc64, (index, part_crc64)| {
+			let start = index * part_size;
+			crate::Crc64::combine(crc64, *part_crc64, (start + part_size).min(object_size) - start)
+		});
+		let verify_error = if temp_size != object_size {
+			Some(anyhow::anyhow!("size mismatch, local: {}, oss: {}", temp_size, object_size))
+		} else {
+			match remote_crc64 {
+				Some(remote) if self.oss_config().enable_crc64 && remote != local_crc64 => Some(
+					crate::Crc64MismatchError {
+						local: local_crc64,
+						remote,
+						request_id: meta.request_id.clone(),
+					}
+					.into(),
+				),
+				_ => None,
+			}
+		};
+		let _ = tokio::fs::remove_file(&checkpoint_file).await;
+		if let Some(e) = verify_error {
+			let _ = tokio::fs::remove_file(&temp_file).await;
+			return Err(e);
+		}
+		tokio::fs::rename(&temp_file, path).await?;
+		Ok(meta)
+	}
+
+	async fn download_file_part(&self, object_name: &str, temp_file: &Path, etag: &str, start: u64, end: u64) -> anyhow::Result<u64> {
+		let object_name = self.oss_config().get_object_name(object_name);
+		let mut request = self.oss_config().get_object_request(reqwest::Method::GET, object_name.as_ref(), None)?;
+		request.headers_mut().insert(reqwest::header::RANGE, crate::types::content_range::to_range_header(&(start..=end))?.try_into()?);
+		request.headers_mut().insert("x-oss-range-behavior", "standard".try_into()?);
+		crate::ConditionalOptions::new().if_match(etag).apply(request.headers_mut(), "")?;
+
+		let response = self.send(request).await?;
+		if response.status() != reqwest::StatusCode::PARTIAL_CONTENT {
+			anyhow::bail!("range bytes={}-{} is not supported, status: {}", start, end, response.status());
+		}
+		let bytes = response.bytes().await?;
+		if bytes.len() as u64 != end - start + 1 {
+			anyhow::bail!("range bytes={}-{} returned {} bytes", start, end, bytes.len());
+		}
+		let crc64 = crate::Crc64::checksum(&bytes);
+
+		let mut file = tokio::fs::OpenOptions::new().write(true).open(temp_file).await?;
+		file.seek(std::io::SeekFrom::Start(start)).await?;
+		file.write_all(&bytes).await?;
+		file.sync_data().await?;
+		Ok(crc64)
+	}
+}
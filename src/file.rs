@@ -6,6 +6,8 @@ pub struct File {
 	pub etag: String,
 	pub last_modified: chrono::DateTime<chrono::Utc>,
 	pub storage_class: String,
+	/// 仅在列举时指定 fetch-owner 才会返回
+	pub owner: Option<crate::Owner>,
 }
 
 impl File {
@@ -17,6 +19,7 @@ impl File {
 			etag: etag.to_string(),
 			last_modified,
 			storage_class: storage_class.to_string(),
+			owner: None,
 		}
 	}
 }
@@ -29,6 +32,10 @@ impl File {
 		let etag = node.descendants().find(|n| n.has_tag_name("ETag")).and_then(|node| node.text()).unwrap_or("").trim_matches('"');
 		let last_modified = node.descendants().find(|n| n.has_tag_name("LastModified")).and_then(|node| node.text()).unwrap_or("");
 		let storage_class = node.descendants().find(|n| n.has_tag_name("StorageClass")).and_then(|node| node.text()).unwrap_or("");
-		Ok(Self::new(name, r#type, size, etag, last_modified.parse()?, storage_class))
+		let owner = match node.children().find(|n| n.has_tag_name("Owner")) {
+			Some(owner_node) => Some(crate::Owner::new_from_xml_node(owner_node)?),
+			None => None,
+		};
+		Ok(Self { owner, ..Self::new(name, r#type, size, etag, last_modified.parse()?, storage_class) })
 	}
 }
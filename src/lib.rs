@@ -1,11 +1,20 @@
 mod bucket;
+mod callback_verifier;
 mod client;
+mod client_builder;
+mod credentials;
+mod download;
 mod file;
 mod folder;
+mod transfer;
 mod types;
+mod upload;
 
 pub use bucket::Bucket;
+pub use callback_verifier::CallbackVerifier;
 pub use client::Client;
+pub use client_builder::ClientBuilder;
+pub use credentials::*;
 pub use file::File;
 pub use folder::Folder;
 pub use types::*;
@@ -0,0 +1,15 @@
+use std::path::{Path, PathBuf};
+
+/// 4xx 错误(超时与时间偏差除外)重试或续传都无法成功
+pub(crate) fn is_fatal_error(error: &anyhow::Error) -> bool {
+	match error.downcast_ref::<crate::OssError>() {
+		Some(oss_error) => oss_error.status.is_client_error() && oss_error.status != reqwest::StatusCode::REQUEST_TIMEOUT && oss_error.status != reqwest::StatusCode::TOO_MANY_REQUESTS && oss_error.code != crate::OssErrorCode::RequestTimeTooSkewed,
+		None => false,
+	}
+}
+
+pub(crate) fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+	let mut path = path.as_os_str().to_owned();
+	path.push(suffix);
+	PathBuf::from(path)
+}
@@ -0,0 +1,119 @@
+/// # 访问权限
+/// `Default` 仅用于对象, 表示继承 bucket 的权限
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Acl {
+	Default,
+	Private,
+	PublicRead,
+	PublicReadWrite,
+	Other(String),
+}
+
+impl Acl {
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::Default => "default",
+			Self::Private => "private",
+			Self::PublicRead => "public-read",
+			Self::PublicReadWrite => "public-read-write",
+			Self::Other(acl) => acl.as_str(),
+		}
+	}
+}
+
+impl From<&str> for Acl {
+	fn from(acl: &str) -> Self {
+		match acl {
+			"default" => Self::Default,
+			"private" => Self::Private,
+			"public-read" => Self::PublicRead,
+			"public-read-write" => Self::PublicReadWrite,
+			acl => Self::Other(acl.to_string()),
+		}
+	}
+}
+
+impl std::fmt::Display for Acl {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// # 访问控制策略
+/// get_object_acl 与 get_bucket_acl 的结果
+/// ```
+/// # use ali_oss::{AccessControlPolicy, Acl};
+/// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <AccessControlPolicy>
+///   <Owner>
+///     <ID>0022012****</ID>
+///     <DisplayName>user_example</DisplayName>
+///   </Owner>
+///   <AccessControlList>
+///     <Grant>public-read</Grant>
+///   </AccessControlList>
+/// </AccessControlPolicy>"#;
+/// let doc = roxmltree::Document::parse(xml).unwrap();
+/// let policy = AccessControlPolicy::new_from_xml_node(doc.root_element()).unwrap();
+/// assert_eq!(policy.grant, Acl::PublicRead);
+/// assert_eq!(policy.owner.display_name, "user_example");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessControlPolicy {
+	pub owner: crate::Owner,
+	pub grant: Acl,
+}
+
+impl AccessControlPolicy {
+	pub fn new_from_xml_node(node: roxmltree::Node) -> anyhow::Result<Self> {
+		let owner = match node.descendants().find(|n| n.has_tag_name("Owner")) {
+			Some(owner_node) => crate::Owner::new_from_xml_node(owner_node)?,
+			None => crate::Owner::new("", ""),
+		};
+		let grant = node.descendants().find(|n| n.has_tag_name("Grant")).and_then(|node| node.text()).ok_or_else(|| anyhow::anyhow!("Grant node not found"))?;
+		Ok(Self { owner, grant: grant.into() })
+	}
+}
+
+/// # 存储类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StorageClass {
+	Standard,
+	IA,
+	Archive,
+	ColdArchive,
+	DeepColdArchive,
+	Other(String),
+}
+
+impl StorageClass {
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::Standard => "Standard",
+			Self::IA => "IA",
+			Self::Archive => "Archive",
+			Self::ColdArchive => "ColdArchive",
+			Self::DeepColdArchive => "DeepColdArchive",
+			Self::Other(storage_class) => storage_class.as_str(),
+		}
+	}
+}
+
+impl From<&str> for StorageClass {
+	fn from(storage_class: &str) -> Self {
+		match storage_class {
+			"Standard" => Self::Standard,
+			"IA" => Self::IA,
+			"Archive" => Self::Archive,
+			"ColdArchive" => Self::ColdArchive,
+			"DeepColdArchive" => Self::DeepColdArchive,
+			storage_class => Self::Other(storage_class.to_string()),
+		}
+	}
+}
+
+impl std::fmt::Display for StorageClass {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
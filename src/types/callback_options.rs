@@ -0,0 +1,104 @@
+use base64::prelude::*;
+use std::collections::BTreeMap;
+
+/// # 回调请求体的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CallbackBodyType {
+	#[default]
+	FormUrlEncoded,
+	Json,
+}
+
+impl CallbackBodyType {
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::FormUrlEncoded => "application/x-www-form-urlencoded",
+			Self::Json => "application/json",
+		}
+	}
+}
+
+/// # 上传回调
+/// body 中可以使用系统变量 `${bucket}`, `${object}`, `${etag}`, `${size}`, `${mimeType}`, `${imageInfo.height}` 等,
+/// 以及通过 var 设置的自定义变量 `${x:name}`
+/// ```
+/// # use ali_oss::{CallbackBodyType, CallbackOptions};
+/// let callback = CallbackOptions::new("https://example.com/oss/callback", r#"{"object":${object},"size":${size},"uid":${x:uid}}"#).body_type(CallbackBodyType::Json).var("uid", "10086");
+/// assert_eq!(callback.vars.get("x:uid").map(|v| v.as_str()), Some("10086"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CallbackOptions {
+	/// 回调地址, 多个地址以 `;` 分隔
+	pub url: String,
+	/// 回调请求的 Host, 为空时使用 url 中的域名
+	pub host: Option<String>,
+	pub body: String,
+	pub body_type: CallbackBodyType,
+	/// 回调地址为 https 时是否发送 SNI
+	pub sni: Option<bool>,
+	/// 自定义变量, key 以 `x:` 开头
+	pub vars: BTreeMap<String, String>,
+}
+
+impl CallbackOptions {
+	pub fn new<U: ToString, B: ToString>(url: U, body: B) -> Self {
+		Self {
+			url: url.to_string(),
+			host: None,
+			body: body.to_string(),
+			body_type: CallbackBodyType::default(),
+			sni: None,
+			vars: BTreeMap::new(),
+		}
+	}
+	pub fn host<T: ToString>(mut self, host: T) -> Self {
+		self.host = Some(host.to_string());
+		self
+	}
+	pub fn body_type(mut self, body_type: CallbackBodyType) -> Self {
+		self.body_type = body_type;
+		self
+	}
+	pub fn sni(mut self, sni: bool) -> Self {
+		self.sni = Some(sni);
+		self
+	}
+	/// key 未以 `x:` 开头时自动加上
+	pub fn var<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
+		let key = key.to_string();
+		let key = if key.starts_with("x:") { key } else { format!("x:{}", key) };
+		self.vars.insert(key, value.to_string());
+		self
+	}
+
+	/// # base64 编码的回调参数, 对应 `x-oss-callback` 与 PostObject 的 callback 字段
+	pub fn encode_callback(&self) -> String {
+		let mut callback = serde_json::json!({
+			"callbackUrl": self.url,
+			"callbackBody": self.body,
+			"callbackBodyType": self.body_type.as_str(),
+		});
+		if let Some(host) = &self.host {
+			callback["callbackHost"] = serde_json::json!(host);
+		}
+		if let Some(sni) = self.sni {
+			callback["callbackSNI"] = serde_json::json!(sni);
+		}
+		BASE64_STANDARD.encode(callback.to_string())
+	}
+	/// # base64 编码的自定义变量, 对应 `x-oss-callback-var`; PostObject 中自定义变量直接作为表单字段
+	pub fn encode_callback_var(&self) -> Option<String> {
+		if self.vars.is_empty() {
+			return None;
+		}
+		Some(BASE64_STANDARD.encode(serde_json::json!(self.vars).to_string()))
+	}
+
+	pub(crate) fn apply(&self, headers: &mut reqwest::header::HeaderMap) -> anyhow::Result<()> {
+		headers.insert("x-oss-callback", self.encode_callback().try_into()?);
+		if let Some(callback_var) = self.encode_callback_var() {
+			headers.insert("x-oss-callback-var", callback_var.try_into()?);
+		}
+		Ok(())
+	}
+}
@@ -1,13 +1,7 @@
 use std::collections::BTreeMap;
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct CanonicalizedHeaders(Option<BTreeMap<String, String>>);
 
-impl Default for CanonicalizedHeaders {
-	fn default() -> Self {
-		Self(None)
-	}
-}
-
 impl CanonicalizedHeaders {
 	pub fn new(map: Option<BTreeMap<String, String>>) -> Self {
 		Self(map)
@@ -28,20 +22,29 @@ impl CanonicalizedHeaders {
 	}
 }
 
-impl From<&reqwest::Request> for CanonicalizedHeaders {
-	fn from(request: &reqwest::Request) -> Self {
+impl TryFrom<&reqwest::Request> for CanonicalizedHeaders {
+	type Error = anyhow::Error;
+
+	/// 用户元数据可能包含 UTF-8 字符; 不是 UTF-8 的 `x-oss-*` 请求头无法签名, 返回错误
+	/// ```
+	/// # use ali_oss::CanonicalizedHeaders;
+	/// let mut request = reqwest::Request::new(reqwest::Method::PUT, "https://bucket.oss-cn-hangzhou.aliyuncs.com/a.txt".parse().unwrap());
+	/// request.headers_mut().insert("x-oss-meta-name", reqwest::header::HeaderValue::from_bytes("名字".as_bytes()).unwrap());
+	/// let headers = CanonicalizedHeaders::try_from(&request).unwrap();
+	/// assert_eq!(headers.string_for_sign().unwrap(), "x-oss-meta-name:名字\n");
+	///
+	/// request.headers_mut().insert("x-oss-meta-name", reqwest::header::HeaderValue::from_bytes(b"\xff").unwrap());
+	/// assert!(CanonicalizedHeaders::try_from(&request).is_err());
+	/// ```
+	fn try_from(request: &reqwest::Request) -> anyhow::Result<Self> {
 		let mut headers = BTreeMap::new();
 		for (k, v) in request.headers() {
 			let key = k.as_str();
 			if key.starts_with("x-oss-") {
-				match v.to_str() {
-					Ok(v) => {
-						headers.insert(key.to_string(), v.to_string());
-					}
-					Err(_) => {}
-				}
+				let v = std::str::from_utf8(v.as_bytes()).map_err(|_| anyhow::anyhow!("header {} is not valid UTF-8 and cannot be signed", key))?;
+				headers.insert(key.to_string(), v.to_string());
 			}
 		}
-		Self::new(if headers.is_empty() { None } else { Some(headers) })
+		Ok(Self::new(if headers.is_empty() { None } else { Some(headers) }))
 	}
 }
@@ -1,3 +1,73 @@
+// 参与 V1 签名的子资源
+// https://help.aliyun.com/zh/oss/developer-reference/include-signatures-in-the-authorization-header
+const SUB_RESOURCES: &[&str] = &[
+	"acl",
+	"append",
+	"asyncFetch",
+	"bucketInfo",
+	"callback",
+	"callback-var",
+	"cname",
+	"comp",
+	"continuation-token",
+	"cors",
+	"delete",
+	"encryption",
+	"endTime",
+	"img",
+	"inventory",
+	"inventoryId",
+	"lifecycle",
+	"live",
+	"location",
+	"logging",
+	"metaQuery",
+	"objectMeta",
+	"partNumber",
+	"policy",
+	"position",
+	"qos",
+	"referer",
+	"replication",
+	"replicationLocation",
+	"replicationProgress",
+	"requestPayment",
+	"response-cache-control",
+	"response-content-disposition",
+	"response-content-encoding",
+	"response-content-language",
+	"response-content-type",
+	"response-expires",
+	"restore",
+	"security-token",
+	"sequential",
+	"startTime",
+	"stat",
+	"status",
+	"style",
+	"styleName",
+	"symlink",
+	"tagging",
+	"transferAcceleration",
+	"uploadId",
+	"uploads",
+	"versionId",
+	"versioning",
+	"versions",
+	"vod",
+	"website",
+	"worm",
+	"wormExtend",
+	"wormId",
+	"x-oss-ac-forward-allow",
+	"x-oss-ac-source-ip",
+	"x-oss-ac-subnet-mask",
+	"x-oss-ac-vpc-id",
+	"x-oss-process",
+	"x-oss-request-payer",
+	"x-oss-traffic-limit",
+];
+
 #[derive(Debug)]
 pub struct CanonicalizedResource(String);
 
@@ -11,6 +81,28 @@ impl CanonicalizedResource {
 	pub fn new<T: ToString>(resource: T) -> Self {
 		Self(resource.to_string())
 	}
+	/// # 资源路径加上按字典序排列的子资源
+	/// ```
+	/// # use ali_oss::CanonicalizedResource;
+	/// let query = vec![("uploadId".to_string(), "abc".to_string()), ("partNumber".to_string(), "1".to_string()), ("prefix".to_string(), "a".to_string())];
+	/// assert_eq!(CanonicalizedResource::new_with_sub_resources("/bucket/key", &query).as_str(), "/bucket/key?partNumber=1&uploadId=abc");
+	/// let query = vec![("acl".to_string(), "".to_string())];
+	/// assert_eq!(CanonicalizedResource::new_with_sub_resources("/bucket/key", &query).as_str(), "/bucket/key?acl");
+	/// ```
+	pub fn new_with_sub_resources(resource: &str, query: &[(String, String)]) -> Self {
+		let mut sub_resources: Vec<&(String, String)> = query.iter().filter(|(k, _)| SUB_RESOURCES.contains(&k.as_str())).collect();
+		sub_resources.sort_by(|a, b| a.0.cmp(&b.0));
+		let mut buf = resource.to_string();
+		for (i, (k, v)) in sub_resources.into_iter().enumerate() {
+			buf.push(if i == 0 { '?' } else { '&' });
+			buf.push_str(k);
+			if !v.is_empty() {
+				buf.push('=');
+				buf.push_str(v);
+			}
+		}
+		Self(buf)
+	}
 	pub fn as_str(&self) -> &str {
 		&self.0
 	}
@@ -0,0 +1,101 @@
+/// # 条件请求
+/// 条件不满足时, If-None-Match/If-Modified-Since 返回 `OssErrorCode::NotModified`(304),
+/// If-Match/If-Unmodified-Since 返回 `OssErrorCode::PreconditionFailed`(412)
+/// ```
+/// # use ali_oss::ConditionalOptions;
+/// let conditions = ConditionalOptions::new().if_none_match("5B3C1A2E053D763E1B002CC607C5****");
+/// assert_eq!(conditions.if_none_match.as_deref(), Some("5B3C1A2E053D763E1B002CC607C5****"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConditionalOptions {
+	pub if_match: Option<String>,
+	pub if_none_match: Option<String>,
+	pub if_modified_since: Option<chrono::DateTime<chrono::Utc>>,
+	pub if_unmodified_since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ConditionalOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn if_match<T: ToString>(mut self, etag: T) -> Self {
+		self.if_match = Some(etag.to_string());
+		self
+	}
+	pub fn if_none_match<T: ToString>(mut self, etag: T) -> Self {
+		self.if_none_match = Some(etag.to_string());
+		self
+	}
+	pub fn if_modified_since(mut self, time: chrono::DateTime<chrono::Utc>) -> Self {
+		self.if_modified_since = Some(time);
+		self
+	}
+	pub fn if_unmodified_since(mut self, time: chrono::DateTime<chrono::Utc>) -> Self {
+		self.if_unmodified_since = Some(time);
+		self
+	}
+
+	/// 写入请求头, prefix 为空时为 `If-*`, 为 `x-oss-copy-source-` 时为拷贝源的条件
+	pub(crate) fn apply(&self, headers: &mut reqwest::header::HeaderMap, prefix: &str) -> anyhow::Result<()> {
+		let header_name = |name: &str| reqwest::header::HeaderName::from_bytes(format!("{}{}", prefix, name).as_bytes());
+		if let Some(etag) = &self.if_match {
+			headers.insert(header_name("if-match")?, quote_etag(etag).try_into()?);
+		}
+		if let Some(etag) = &self.if_none_match {
+			headers.insert(header_name("if-none-match")?, quote_etag(etag).try_into()?);
+		}
+		if let Some(time) = &self.if_modified_since {
+			headers.insert(header_name("if-modified-since")?, time.format("%a, %d %b %Y %T GMT").to_string().try_into()?);
+		}
+		if let Some(time) = &self.if_unmodified_since {
+			headers.insert(header_name("if-unmodified-since")?, time.format("%a, %d %b %Y %T GMT").to_string().try_into()?);
+		}
+		Ok(())
+	}
+}
+
+/// `File`/`Part` 中的 ETag 已去掉引号, 请求头中需要加回
+fn quote_etag(etag: &str) -> String {
+	if etag == "*" || etag.starts_with('"') || etag.starts_with("W/") {
+		etag.to_string()
+	} else {
+		format!("\"{}\"", etag)
+	}
+}
+
+/// # copy_object 参数
+/// ```
+/// # use ali_oss::{ConditionalOptions, CopyDirective, CopyObjectOptions, PutObjectOptions};
+/// let options = CopyObjectOptions::new().source_conditions(ConditionalOptions::new().if_match("5B3C1A2E053D763E1B002CC607C5****")).metadata_directive(CopyDirective::Replace).put_options(PutObjectOptions::new().meta("author", "ali-oss"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CopyObjectOptions {
+	/// 拷贝源的条件, 对应 `x-oss-copy-source-if-*`
+	pub source_conditions: ConditionalOptions,
+	/// 目标对象的请求头; 元数据与标签只有在对应的 directive 为 Replace 时生效
+	pub put_options: crate::PutObjectOptions,
+	pub metadata_directive: Option<crate::CopyDirective>,
+	pub tagging_directive: Option<crate::CopyDirective>,
+}
+
+impl CopyObjectOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn source_conditions(mut self, source_conditions: ConditionalOptions) -> Self {
+		self.source_conditions = source_conditions;
+		self
+	}
+	pub fn put_options(mut self, put_options: crate::PutObjectOptions) -> Self {
+		self.put_options = put_options;
+		self
+	}
+	pub fn metadata_directive(mut self, metadata_directive: crate::CopyDirective) -> Self {
+		self.metadata_directive = Some(metadata_directive);
+		self
+	}
+	pub fn tagging_directive(mut self, tagging_directive: crate::CopyDirective) -> Self {
+		self.tagging_directive = Some(tagging_directive);
+		self
+	}
+}
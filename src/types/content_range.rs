@@ -0,0 +1,74 @@
+use std::ops::{Bound, RangeBounds};
+
+/// # 响应的 Content-Range
+/// ```
+/// # use ali_oss::ContentRange;
+/// let content_range = ContentRange::parse("bytes 0-99/1000").unwrap();
+/// assert_eq!((content_range.start, content_range.end, content_range.total), (0, 99, Some(1000)));
+/// assert_eq!(content_range.len(), 100);
+/// // 空对象
+/// let empty = ContentRange::new_from_len(0);
+/// assert!(empty.is_empty());
+/// assert_eq!(empty.len(), 0);
+/// assert_eq!(ContentRange { start: 10, end: 9, total: None }.len(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentRange {
+	pub start: u64,
+	/// 包含 end
+	pub end: u64,
+	/// 对象总大小, 未知时为 None
+	pub total: Option<u64>,
+}
+
+impl ContentRange {
+	pub fn parse(value: &str) -> anyhow::Result<Self> {
+		let invalid = || anyhow::anyhow!("invalid Content-Range: {}", value);
+		let (range, total) = value.trim().strip_prefix("bytes ").ok_or_else(invalid)?.split_once('/').ok_or_else(invalid)?;
+		let (start, end) = range.split_once('-').ok_or_else(invalid)?;
+		Ok(Self {
+			start: start.parse()?,
+			end: end.parse()?,
+			total: if total == "*" { None } else { Some(total.parse()?) },
+		})
+	}
+	/// # 整个对象的范围
+	/// 空对象的范围为空, 表示为 `0-0/0`
+	pub fn new_from_len(len: u64) -> Self {
+		Self {
+			start: 0,
+			end: len.saturating_sub(1),
+			total: Some(len),
+		}
+	}
+	pub fn len(&self) -> u64 {
+		if self.is_empty() {
+			return 0;
+		}
+		self.end - self.start + 1
+	}
+	/// end 小于 start 或对象大小为 0 时为空
+	pub fn is_empty(&self) -> bool {
+		self.end < self.start || self.total == Some(0)
+	}
+}
+
+/// 转换为 Range 请求头, 如 `0..100` 为 `bytes=0-99`, `100..` 为 `bytes=100-`
+pub(crate) fn to_range_header<R: RangeBounds<u64>>(range: &R) -> anyhow::Result<String> {
+	let start = match range.start_bound() {
+		Bound::Included(start) => *start,
+		Bound::Excluded(start) => start.checked_add(1).ok_or_else(|| anyhow::anyhow!("empty range"))?,
+		Bound::Unbounded => 0,
+	};
+	let end = match range.end_bound() {
+		Bound::Included(end) => Some(*end),
+		Bound::Excluded(0) => anyhow::bail!("empty range"),
+		Bound::Excluded(end) => Some(end - 1),
+		Bound::Unbounded => None,
+	};
+	match end {
+		Some(end) if end < start => anyhow::bail!("empty range"),
+		Some(end) => Ok(format!("bytes={}-{}", start, end)),
+		None => Ok(format!("bytes={}-", start)),
+	}
+}
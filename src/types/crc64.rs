@@ -0,0 +1,167 @@
+// CRC-64/XZ (ECMA-182 多项式, 反射输入输出, 初始值与结果异或值均为全 1), 与 OSS 返回的 x-oss-hash-crc64ecma 一致
+const POLY: u64 = 0xC96C_5795_D787_0F42;
+
+const TABLE: [u64; 256] = {
+	let mut table = [0u64; 256];
+	let mut i = 0;
+	while i < 256 {
+		let mut crc = i as u64;
+		let mut j = 0;
+		while j < 8 {
+			crc = if crc & 1 == 1 { (crc >> 1) ^ POLY } else { crc >> 1 };
+			j += 1;
+		}
+		table[i] = crc;
+		i += 1;
+	}
+	table
+};
+
+/// # CRC64-ECMA
+/// ```
+/// # use ali_oss::Crc64;
+/// let mut crc64 = Crc64::new();
+/// crc64.update(b"1234");
+/// crc64.update(b"56789");
+/// assert_eq!(crc64.finalize(), 0x995DC9BBDF1939FA);
+/// assert_eq!(Crc64::combine(Crc64::checksum(b"1234"), Crc64::checksum(b"56789"), 5), Crc64::checksum(b"123456789"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Crc64 {
+	value: u64,
+}
+
+impl Crc64 {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	/// 从已有的 CRC 值继续计算
+	pub fn new_with_initial(value: u64) -> Self {
+		Self { value }
+	}
+	pub fn update(&mut self, bytes: &[u8]) {
+		let mut crc = !self.value;
+		for &byte in bytes {
+			crc = TABLE[((crc ^ byte as u64) & 0xFF) as usize] ^ (crc >> 8);
+		}
+		self.value = !crc;
+	}
+	pub fn finalize(&self) -> u64 {
+		self.value
+	}
+	pub fn checksum(bytes: &[u8]) -> u64 {
+		let mut crc64 = Self::new();
+		crc64.update(bytes);
+		crc64.finalize()
+	}
+	/// # 合并两段数据的 CRC
+	/// crc1 为前一段数据的 CRC, crc2 为后一段(长度为 len2)数据的 CRC, 算法参考 zlib 的 crc32_combine
+	pub fn combine(crc1: u64, crc2: u64, len2: u64) -> u64 {
+		if len2 == 0 {
+			return crc1;
+		}
+		// odd 为移位 1 个零比特的算子, even 为移位 2 个零比特的算子
+		let mut odd = [0u64; 64];
+		let mut even = [0u64; 64];
+		odd[0] = POLY;
+		let mut row = 1u64;
+		for item in odd.iter_mut().skip(1) {
+			*item = row;
+			row <<= 1;
+		}
+		gf2_matrix_square(&mut even, &odd);
+		gf2_matrix_square(&mut odd, &even);
+
+		let mut crc1 = crc1;
+		let mut len2 = len2;
+		loop {
+			gf2_matrix_square(&mut even, &odd);
+			if len2 & 1 == 1 {
+				crc1 = gf2_matrix_times(&even, crc1);
+			}
+			len2 >>= 1;
+			if len2 == 0 {
+				break;
+			}
+			gf2_matrix_square(&mut odd, &even);
+			if len2 & 1 == 1 {
+				crc1 = gf2_matrix_times(&odd, crc1);
+			}
+			len2 >>= 1;
+			if len2 == 0 {
+				break;
+			}
+		}
+		crc1 ^ crc2
+	}
+}
+
+fn gf2_matrix_times(matrix: &[u64; 64], vector: u64) -> u64 {
+	let mut sum = 0u64;
+	let mut vector = vector;
+	let mut i = 0;
+	while vector != 0 {
+		if vector & 1 == 1 {
+			sum ^= matrix[i];
+		}
+		vector >>= 1;
+		i += 1;
+	}
+	sum
+}
+
+fn gf2_matrix_square(square: &mut [u64; 64], matrix: &[u64; 64]) {
+	for i in 0..64 {
+		square[i] = gf2_matrix_times(matrix, matrix[i]);
+	}
+}
+
+/// # CRC64 校验失败
+/// 本地计算的 CRC64 与 OSS 返回的 x-oss-hash-crc64ecma 不一致, 可以通过 `downcast_ref::<Crc64MismatchError>()` 匹配
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crc64MismatchError {
+	pub local: u64,
+	pub remote: u64,
+	pub request_id: String,
+}
+
+impl std::fmt::Display for Crc64MismatchError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "crc64 mismatch, local: {}, oss: {}, request id: {}", self.local, self.remote, self.request_id)
+	}
+}
+
+impl std::error::Error for Crc64MismatchError {}
+
+/// 读取响应头中的 x-oss-hash-crc64ecma
+pub(crate) fn remote_crc64(headers: &reqwest::header::HeaderMap) -> Option<u64> {
+	headers.get("x-oss-hash-crc64ecma").and_then(|v| v.to_str().ok()).and_then(|v| v.parse().ok())
+}
+
+/// 与响应头中的 CRC64 比较, 响应头中没有 CRC64 时不校验
+pub(crate) fn verify(local: u64, headers: &reqwest::header::HeaderMap) -> anyhow::Result<()> {
+	match remote_crc64(headers) {
+		Some(remote) if remote != local => Err(Crc64MismatchError {
+			local,
+			remote,
+			request_id: headers.get("x-oss-request-id").and_then(|v| v.to_str().ok()).unwrap_or("").to_string(),
+		}
+		.into()),
+		_ => Ok(()),
+	}
+}
+
+/// # 边读边计算 CRC64 的流
+/// 用于流式上传, 计算结果通过共享的 `Arc<Mutex<Crc64>>` 读取
+pub(crate) fn hash_stream<S>(stream: S, crc64: std::sync::Arc<std::sync::Mutex<Crc64>>) -> impl futures::Stream<Item = reqwest::Result<bytes::Bytes>> + Send + Sync + 'static
+where
+	S: futures::Stream<Item = reqwest::Result<bytes::Bytes>> + Send + Sync + 'static,
+{
+	use futures::StreamExt;
+	stream.map(move |chunk| {
+		if let Ok(bytes) = &chunk {
+			crc64.lock().unwrap().update(bytes);
+		}
+		chunk
+	})
+}
@@ -0,0 +1,108 @@
+use reqwest::Url;
+
+/// # bucket 的寻址方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AddressingStyle {
+	/// `{bucket}.{host}/{object}`
+	#[default]
+	VirtualHosted,
+	/// 自定义域名已绑定到 bucket: `{host}/{object}`
+	Cname,
+	/// `{host}/{bucket}/{object}`, 适用于本地模拟服务
+	PathStyle,
+}
+
+/// # 自定义访问域名
+/// ```
+/// # use ali_oss::{AddressingStyle, Endpoint};
+/// # use reqwest::Url;
+/// let endpoint = Endpoint::parse("http://127.0.0.1:9000").unwrap().addressing_style(AddressingStyle::PathStyle);
+/// assert_eq!(endpoint.get_bucket_url("hello").unwrap(), Url::parse("http://127.0.0.1:9000/hello/").unwrap());
+/// let endpoint = Endpoint::parse("oss-cn-hangzhou.aliyuncs.com").unwrap();
+/// assert_eq!(endpoint.get_bucket_url("hello").unwrap(), Url::parse("https://hello.oss-cn-hangzhou.aliyuncs.com").unwrap());
+/// let endpoint = Endpoint::parse("https://static.example.com").unwrap().addressing_style(AddressingStyle::Cname);
+/// assert_eq!(endpoint.get_bucket_url("hello").unwrap(), Url::parse("https://static.example.com").unwrap());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoint {
+	pub scheme: String,
+	pub host: String,
+	pub port: Option<u16>,
+	pub addressing_style: AddressingStyle,
+}
+
+impl Endpoint {
+	pub fn new<T: ToString>(scheme: T, host: T, port: Option<u16>) -> Self {
+		Self {
+			scheme: scheme.to_string(),
+			host: host.to_string(),
+			port,
+			addressing_style: AddressingStyle::default(),
+		}
+	}
+	/// 解析 `[scheme://]host[:port]`, 未指定 scheme 时使用 https
+	pub fn parse(endpoint: &str) -> anyhow::Result<Self> {
+		let endpoint = if endpoint.contains("://") { endpoint.to_string() } else { format!("https://{}", endpoint) };
+		let url = Url::parse(&endpoint)?;
+		let host = url.host_str().ok_or_else(|| anyhow::anyhow!("host not found in endpoint {}", endpoint))?;
+		Ok(Self::new(url.scheme(), host, url.port()))
+	}
+	pub fn addressing_style(mut self, addressing_style: AddressingStyle) -> Self {
+		self.addressing_style = addressing_style;
+		self
+	}
+	fn get_host_with_port(&self, host: &str) -> String {
+		match self.port {
+			Some(port) => format!("{}:{}", host, port),
+			None => host.to_string(),
+		}
+	}
+	/// # 不属于任何 bucket 的请求地址, 如 list_buckets
+	pub fn get_service_url(&self) -> anyhow::Result<Url> {
+		Ok(Url::parse(&format!("{}://{}", self.scheme, self.get_host_with_port(&self.host)))?)
+	}
+	pub fn get_bucket_url(&self, bucket_name: &str) -> anyhow::Result<Url> {
+		let src = match self.addressing_style {
+			AddressingStyle::VirtualHosted => format!("{}://{}", self.scheme, self.get_host_with_port(&format!("{}.{}", bucket_name, self.host))),
+			AddressingStyle::Cname => format!("{}://{}", self.scheme, self.get_host_with_port(&self.host)),
+			AddressingStyle::PathStyle => format!("{}://{}/{}/", self.scheme, self.get_host_with_port(&self.host), bucket_name),
+		};
+		Ok(Url::parse(&src)?)
+	}
+	/// # 切换到另一个地域的 bucket 时使用的访问域名
+	/// CNAME 只绑定了原 bucket, 返回 None 以使用默认域名; 包含原地域的域名(如 `oss-cn-hangzhou-internal.aliyuncs.com`)替换为新地域
+	pub(crate) fn for_location(&self, location: &str, new_location: &str) -> Option<Self> {
+		match self.addressing_style {
+			AddressingStyle::Cname => None,
+			AddressingStyle::VirtualHosted => {
+				let mut endpoint = self.clone();
+				if let Some(suffix) = self.host.strip_prefix(location).filter(|suffix| suffix.starts_with('.') || suffix.starts_with("-internal.")) {
+					endpoint.host = format!("{}{}", new_location, suffix);
+				}
+				Some(endpoint)
+			}
+			AddressingStyle::PathStyle => Some(self.clone()),
+		}
+	}
+	/// # 请求路径对应的 bucket 与 object
+	/// 返回 None 表示请求不属于任何 bucket
+	pub(crate) fn get_resource_path(&self, bucket_name: &str, url: &Url, decoded_path: &str) -> Option<String> {
+		match self.addressing_style {
+			AddressingStyle::VirtualHosted => {
+				if url.host_str() == Some(self.host.as_str()) {
+					None
+				} else {
+					Some(format!("/{}{}", bucket_name, decoded_path))
+				}
+			}
+			AddressingStyle::Cname => Some(format!("/{}{}", bucket_name, decoded_path)),
+			AddressingStyle::PathStyle => {
+				if decoded_path == "/" {
+					None
+				} else {
+					Some(decoded_path.to_string())
+				}
+			}
+		}
+	}
+}
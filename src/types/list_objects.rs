@@ -0,0 +1,117 @@
+use percent_encoding::percent_decode_str;
+
+/// # ListObjectsV2 请求参数
+/// ```
+/// # use ali_oss::ListObjectsOptions;
+/// let options = ListObjectsOptions::new().prefix("images/").delimiter("/").max_keys(100).fetch_owner(true);
+/// assert_eq!(options.max_keys, Some(100));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ListObjectsOptions {
+	pub prefix: Option<String>,
+	pub delimiter: Option<String>,
+	/// 单页最多返回的数量, 取值 1~1000, 默认 100
+	pub max_keys: Option<u32>,
+	pub continuation_token: Option<String>,
+	pub start_after: Option<String>,
+	pub fetch_owner: bool,
+	/// 使用 encoding-type=url, 返回结果会自动解码; 对象名包含 XML 1.0 不支持的字符时需要开启
+	pub encoding_url: bool,
+}
+
+impl ListObjectsOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn prefix<T: ToString>(mut self, prefix: T) -> Self {
+		self.prefix = Some(prefix.to_string());
+		self
+	}
+	pub fn delimiter<T: ToString>(mut self, delimiter: T) -> Self {
+		self.delimiter = Some(delimiter.to_string());
+		self
+	}
+	pub fn max_keys(mut self, max_keys: u32) -> Self {
+		self.max_keys = Some(max_keys);
+		self
+	}
+	pub fn continuation_token<T: ToString>(mut self, continuation_token: T) -> Self {
+		self.continuation_token = Some(continuation_token.to_string());
+		self
+	}
+	pub fn start_after<T: ToString>(mut self, start_after: T) -> Self {
+		self.start_after = Some(start_after.to_string());
+		self
+	}
+	pub fn fetch_owner(mut self, fetch_owner: bool) -> Self {
+		self.fetch_owner = fetch_owner;
+		self
+	}
+	pub fn encoding_url(mut self, encoding_url: bool) -> Self {
+		self.encoding_url = encoding_url;
+		self
+	}
+}
+
+/// # ListObjectsV2 单页结果
+#[derive(Debug, Clone)]
+pub struct ListObjectsResult {
+	pub name: String,
+	pub prefix: String,
+	pub delimiter: String,
+	pub start_after: String,
+	pub max_keys: u32,
+	pub key_count: u32,
+	pub is_truncated: bool,
+	pub continuation_token: Option<String>,
+	pub next_continuation_token: Option<String>,
+	pub folders: Vec<crate::Folder>,
+	pub files: Vec<crate::File>,
+}
+
+impl ListObjectsResult {
+	pub fn new_from_xml_node(node: roxmltree::Node) -> anyhow::Result<Self> {
+		let text = |tag: &str| node.children().find(|n| n.has_tag_name(tag)).and_then(|node| node.text()).map(|text| text.to_string());
+		let url_encoded = text("EncodingType").is_some_and(|encoding_type| encoding_type == "url");
+		let decode = |value: String| -> anyhow::Result<String> {
+			if url_encoded {
+				Ok(percent_decode_str(&value.replace('+', " ")).decode_utf8()?.into_owned())
+			} else {
+				Ok(value)
+			}
+		};
+
+		let mut folders = Vec::new();
+		for folder_node in node.children().filter(|n| n.has_tag_name("CommonPrefixes")) {
+			let mut folder = crate::Folder::new_from_xml_node(folder_node)?;
+			folder.name = decode(folder.name)?;
+			folders.push(folder);
+		}
+		let mut files = Vec::new();
+		for file_node in node.children().filter(|n| n.has_tag_name("Contents")) {
+			let mut file = crate::File::new_from_xml_node(file_node)?;
+			file.name = decode(file.name)?;
+			files.push(file);
+		}
+		Ok(Self {
+			name: text("Name").unwrap_or_default(),
+			prefix: decode(text("Prefix").unwrap_or_default())?,
+			delimiter: decode(text("Delimiter").unwrap_or_default())?,
+			start_after: decode(text("StartAfter").unwrap_or_default())?,
+			max_keys: text("MaxKeys").unwrap_or_default().parse().unwrap_or_default(),
+			key_count: text("KeyCount").unwrap_or_default().parse().unwrap_or_default(),
+			is_truncated: text("IsTruncated").is_some_and(|is_truncated| is_truncated == "true"),
+			continuation_token: text("ContinuationToken"),
+			next_continuation_token: text("NextContinuationToken"),
+			folders,
+			files,
+		})
+	}
+}
+
+/// # 列举结果中的单个条目
+#[derive(Debug, Clone)]
+pub enum ListObjectsItem {
+	Folder(crate::Folder),
+	File(crate::File),
+}
@@ -0,0 +1,106 @@
+// 常见扩展名对应的 MIME 类型, 扩展名为小写且不含 `.`
+static MIME_TYPES: &[(&str, &str)] = &[
+	// 文本与网页
+	("html", "text/html"),
+	("htm", "text/html"),
+	("shtml", "text/html"),
+	("xhtml", "application/xhtml+xml"),
+	("css", "text/css"),
+	("js", "text/javascript"),
+	("mjs", "text/javascript"),
+	("json", "application/json"),
+	("map", "application/json"),
+	("jsonld", "application/ld+json"),
+	("webmanifest", "application/manifest+json"),
+	("xml", "application/xml"),
+	("xsl", "application/xml"),
+	("txt", "text/plain"),
+	("text", "text/plain"),
+	("log", "text/plain"),
+	("md", "text/markdown"),
+	("markdown", "text/markdown"),
+	("csv", "text/csv"),
+	("tsv", "text/tab-separated-values"),
+	("ics", "text/calendar"),
+	("vtt", "text/vtt"),
+	("yaml", "application/yaml"),
+	("yml", "application/yaml"),
+	("rss", "application/rss+xml"),
+	("atom", "application/atom+xml"),
+	("wasm", "application/wasm"),
+	// 图片
+	("png", "image/png"),
+	("jpg", "image/jpeg"),
+	("jpeg", "image/jpeg"),
+	("gif", "image/gif"),
+	("webp", "image/webp"),
+	("avif", "image/avif"),
+	("svg", "image/svg+xml"),
+	("svgz", "image/svg+xml"),
+	("ico", "image/x-icon"),
+	("bmp", "image/bmp"),
+	("tif", "image/tiff"),
+	("tiff", "image/tiff"),
+	("heic", "image/heic"),
+	("heif", "image/heif"),
+	// 字体
+	("woff", "font/woff"),
+	("woff2", "font/woff2"),
+	("ttf", "font/ttf"),
+	("otf", "font/otf"),
+	("eot", "application/vnd.ms-fontobject"),
+	// 音视频
+	("mp3", "audio/mpeg"),
+	("wav", "audio/wav"),
+	("ogg", "audio/ogg"),
+	("oga", "audio/ogg"),
+	("flac", "audio/flac"),
+	("aac", "audio/aac"),
+	("m4a", "audio/mp4"),
+	("mp4", "video/mp4"),
+	("m4v", "video/mp4"),
+	("webm", "video/webm"),
+	("ogv", "video/ogg"),
+	("mov", "video/quicktime"),
+	("avi", "video/x-msvideo"),
+	("flv", "video/x-flv"),
+	("mkv", "video/x-matroska"),
+	("m3u8", "application/vnd.apple.mpegurl"),
+	("ts", "video/mp2t"),
+	// 文档
+	("pdf", "application/pdf"),
+	("doc", "application/msword"),
+	("docx", "application/vnd.openxmlformats-officedocument.wordprocessingml.document"),
+	("xls", "application/vnd.ms-excel"),
+	("xlsx", "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"),
+	("ppt", "application/vnd.ms-powerpoint"),
+	("pptx", "application/vnd.openxmlformats-officedocument.presentationml.presentation"),
+	("rtf", "application/rtf"),
+	("epub", "application/epub+zip"),
+	// 压缩包与二进制
+	("zip", "application/zip"),
+	("gz", "application/gzip"),
+	("tgz", "application/gzip"),
+	("tar", "application/x-tar"),
+	("bz2", "application/x-bzip2"),
+	("xz", "application/x-xz"),
+	("7z", "application/x-7z-compressed"),
+	("rar", "application/vnd.rar"),
+	("apk", "application/vnd.android.package-archive"),
+	("exe", "application/octet-stream"),
+	("bin", "application/octet-stream"),
+];
+
+/// 对象名称的扩展名, 转为小写
+pub(crate) fn extension(object_name: &str) -> Option<String> {
+	let file_name = object_name.rsplit('/').next().unwrap_or(object_name);
+	match file_name.rsplit_once('.') {
+		Some((_, extension)) if !extension.is_empty() => Some(extension.to_lowercase()),
+		_ => None,
+	}
+}
+
+/// 根据扩展名查找内置的 MIME 类型
+pub(crate) fn from_extension(extension: &str) -> Option<&'static str> {
+	MIME_TYPES.iter().find(|(ext, _)| *ext == extension).map(|(_, mime)| *mime)
+}
@@ -1,15 +1,50 @@
+mod acl;
 mod bucket_location;
 mod bucket_stat;
+mod callback_options;
 mod canonicalized_headers;
 mod canonicalized_resource;
+mod conditional_options;
+pub(crate) mod content_range;
+pub(crate) mod crc64;
+mod download_file_options;
+mod endpoint;
+mod list_objects;
+pub(crate) mod mime;
+mod multipart;
+mod object_meta;
 mod oss_config;
-// mod oss_error;
-mod signature;
+mod oss_error;
+mod owner;
+mod post_policy;
+mod presign_options;
+mod put_bucket_options;
+mod put_object_options;
+pub(crate) mod retry_policy;
+pub(crate) mod signature;
+mod upload_file_options;
 
+pub use acl::{AccessControlPolicy, Acl, StorageClass};
 pub use bucket_location::BucketLocation;
 pub use bucket_stat::BucketStat;
+pub use callback_options::{CallbackBodyType, CallbackOptions};
 pub use canonicalized_headers::CanonicalizedHeaders;
 pub use canonicalized_resource::CanonicalizedResource;
+pub use conditional_options::{ConditionalOptions, CopyObjectOptions};
+pub use content_range::ContentRange;
+pub use crc64::{Crc64, Crc64MismatchError};
+pub use download_file_options::DownloadFileOptions;
+pub use endpoint::{AddressingStyle, Endpoint};
+pub use list_objects::{ListObjectsItem, ListObjectsOptions, ListObjectsResult};
+pub use multipart::{CompleteMultipartUploadResult, InitiateMultipartUploadResult, ListMultipartUploadsOptions, ListMultipartUploadsResult, ListPartsResult, MultipartUpload, Part};
+pub use object_meta::{CopyObjectOutput, ObjectMeta, ObjectType, PutObjectOutput};
 pub use oss_config::OssConfig;
-// pub use oss_error::OssError;
-pub use signature::{HeaderSignature, ParamSignature, SignatureAble};
+pub use oss_error::{OssError, OssErrorCode};
+pub use owner::Owner;
+pub use post_policy::{PostObjectForm, PostPolicy};
+pub use presign_options::PresignOptions;
+pub use put_bucket_options::{DataRedundancyType, PutBucketOptions};
+pub use put_object_options::{CopyDirective, PutObjectOptions};
+pub use retry_policy::{ExponentialBackoff, NoRetry, RetryPolicy};
+pub use signature::{HeaderSignature, HeaderSignatureV4, ParamSignature, ParamSignatureV4, SignatureAble, SignatureVersion};
+pub use upload_file_options::UploadFileOptions;
@@ -0,0 +1,264 @@
+/// # 初始化分片上传的结果
+#[derive(Debug, Clone)]
+pub struct InitiateMultipartUploadResult {
+	pub bucket: String,
+	pub key: String,
+	pub upload_id: String,
+}
+
+impl InitiateMultipartUploadResult {
+	pub fn new_from_xml_node(node: roxmltree::Node) -> anyhow::Result<Self> {
+		let bucket = node.descendants().find(|n| n.has_tag_name("Bucket")).and_then(|node| node.text()).unwrap_or("");
+		let key = node.descendants().find(|n| n.has_tag_name("Key")).and_then(|node| node.text()).unwrap_or("");
+		let upload_id = node.descendants().find(|n| n.has_tag_name("UploadId")).and_then(|node| node.text()).unwrap_or("");
+		if upload_id.is_empty() {
+			anyhow::bail!("missing UploadId in InitiateMultipartUploadResult");
+		}
+		Ok(Self {
+			bucket: bucket.to_string(),
+			key: key.to_string(),
+			upload_id: upload_id.to_string(),
+		})
+	}
+}
+
+/// # 已上传的分片
+/// `upload_part` 返回时 size 与 last_modified 为空, `list_parts` 返回时全部字段都有值
+#[derive(Debug, Clone)]
+pub struct Part {
+	pub part_number: u32,
+	pub etag: String,
+	pub size: Option<u64>,
+	pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
+	pub crc64: Option<u64>,
+}
+
+impl Part {
+	pub fn new<T: ToString>(part_number: u32, etag: T) -> Self {
+		Self {
+			part_number,
+			etag: etag.to_string().trim_matches('"').to_string(),
+			size: None,
+			last_modified: None,
+			crc64: None,
+		}
+	}
+	pub fn new_from_headers(part_number: u32, headers: &reqwest::header::HeaderMap) -> anyhow::Result<Self> {
+		let etag = headers.get(reqwest::header::ETAG).ok_or_else(|| anyhow::anyhow!("missing ETag of part {}", part_number))?.to_str()?;
+		let crc64 = match headers.get("x-oss-hash-crc64ecma") {
+			Some(crc64) => Some(crc64.to_str()?.parse()?),
+			None => None,
+		};
+		Ok(Self { crc64, ..Self::new(part_number, etag) })
+	}
+	pub fn new_from_xml_node(node: roxmltree::Node) -> anyhow::Result<Self> {
+		let part_number = node.descendants().find(|n| n.has_tag_name("PartNumber")).and_then(|node| node.text()).unwrap_or("").parse()?;
+		let etag = node.descendants().find(|n| n.has_tag_name("ETag")).and_then(|node| node.text()).unwrap_or("");
+		let size = node.descendants().find(|n| n.has_tag_name("Size")).and_then(|node| node.text()).unwrap_or("").parse()?;
+		let last_modified = node.descendants().find(|n| n.has_tag_name("LastModified")).and_then(|node| node.text()).unwrap_or("").parse()?;
+		let crc64 = match node.descendants().find(|n| n.has_tag_name("HashCrc64ecma")).and_then(|node| node.text()) {
+			Some(crc64) => Some(crc64.parse()?),
+			None => None,
+		};
+		Ok(Self {
+			size: Some(size),
+			last_modified: Some(last_modified),
+			crc64,
+			..Self::new(part_number, etag)
+		})
+	}
+}
+
+/// # 完成分片上传的结果
+#[derive(Debug, Clone)]
+pub struct CompleteMultipartUploadResult {
+	pub request_id: String,
+	pub bucket: String,
+	pub key: String,
+	pub etag: String,
+	pub location: String,
+	pub crc64: Option<u64>,
+	/// 设置了上传回调时, 回调服务器返回的内容
+	pub callback_body: Option<String>,
+	/// 对象已合并但回调失败(203 CallbackFailed)时的错误
+	pub callback_error: Option<crate::OssError>,
+}
+
+impl CompleteMultipartUploadResult {
+	pub fn new_from_xml_node(node: roxmltree::Node, headers: &reqwest::header::HeaderMap) -> anyhow::Result<Self> {
+		let bucket = node.descendants().find(|n| n.has_tag_name("Bucket")).and_then(|node| node.text()).unwrap_or("");
+		let key = node.descendants().find(|n| n.has_tag_name("Key")).and_then(|node| node.text()).unwrap_or("");
+		let etag = node.descendants().find(|n| n.has_tag_name("ETag")).and_then(|node| node.text()).unwrap_or("").trim_matches('"');
+		let location = node.descendants().find(|n| n.has_tag_name("Location")).and_then(|node| node.text()).unwrap_or("");
+		let crc64 = match headers.get("x-oss-hash-crc64ecma") {
+			Some(crc64) => Some(crc64.to_str()?.parse()?),
+			None => None,
+		};
+		Ok(Self {
+			request_id: get_request_id(headers),
+			bucket: bucket.to_string(),
+			key: key.to_string(),
+			etag: etag.to_string(),
+			location: location.to_string(),
+			crc64,
+			callback_body: None,
+			callback_error: None,
+		})
+	}
+	/// 设置了上传回调时响应体为回调服务器返回的内容, 结果只能来自响应头
+	pub fn new_from_headers(bucket: &str, key: &str, headers: &reqwest::header::HeaderMap) -> anyhow::Result<Self> {
+		let etag = headers.get(reqwest::header::ETAG).map(|v| v.to_str()).transpose()?.unwrap_or("").trim_matches('"');
+		let crc64 = match headers.get("x-oss-hash-crc64ecma") {
+			Some(crc64) => Some(crc64.to_str()?.parse()?),
+			None => None,
+		};
+		Ok(Self {
+			request_id: get_request_id(headers),
+			bucket: bucket.to_string(),
+			key: key.to_string(),
+			etag: etag.to_string(),
+			location: "".to_string(),
+			crc64,
+			callback_body: None,
+			callback_error: None,
+		})
+	}
+}
+
+fn get_request_id(headers: &reqwest::header::HeaderMap) -> String {
+	headers.get("x-oss-request-id").and_then(|v| v.to_str().ok()).unwrap_or("").to_string()
+}
+
+/// # 列举分片的结果
+#[derive(Debug, Clone)]
+pub struct ListPartsResult {
+	pub bucket: String,
+	pub key: String,
+	pub upload_id: String,
+	pub part_number_marker: u32,
+	pub next_part_number_marker: u32,
+	pub max_parts: u32,
+	pub is_truncated: bool,
+	pub parts: Vec<Part>,
+}
+
+impl ListPartsResult {
+	pub fn new_from_xml_node(node: roxmltree::Node) -> anyhow::Result<Self> {
+		let text = |tag: &str| node.children().find(|n| n.has_tag_name(tag)).and_then(|node| node.text()).unwrap_or("");
+		let mut parts = Vec::new();
+		for part_node in node.children().filter(|n| n.has_tag_name("Part")) {
+			parts.push(Part::new_from_xml_node(part_node)?);
+		}
+		Ok(Self {
+			bucket: text("Bucket").to_string(),
+			key: text("Key").to_string(),
+			upload_id: text("UploadId").to_string(),
+			part_number_marker: text("PartNumberMarker").parse().unwrap_or_default(),
+			next_part_number_marker: text("NextPartNumberMarker").parse().unwrap_or_default(),
+			max_parts: text("MaxParts").parse().unwrap_or_default(),
+			is_truncated: text("IsTruncated") == "true",
+			parts,
+		})
+	}
+}
+
+/// # 进行中的分片上传事件
+#[derive(Debug, Clone)]
+pub struct MultipartUpload {
+	pub key: String,
+	pub upload_id: String,
+	pub initiated: chrono::DateTime<chrono::Utc>,
+}
+
+impl MultipartUpload {
+	pub fn new_from_xml_node(node: roxmltree::Node) -> anyhow::Result<Self> {
+		let key = node.descendants().find(|n| n.has_tag_name("Key")).and_then(|node| node.text()).unwrap_or("");
+		let upload_id = node.descendants().find(|n| n.has_tag_name("UploadId")).and_then(|node| node.text()).unwrap_or("");
+		let initiated = node.descendants().find(|n| n.has_tag_name("Initiated")).and_then(|node| node.text()).unwrap_or("").parse()?;
+		Ok(Self {
+			key: key.to_string(),
+			upload_id: upload_id.to_string(),
+			initiated,
+		})
+	}
+}
+
+/// # ListMultipartUploads 请求参数
+#[derive(Debug, Clone, Default)]
+pub struct ListMultipartUploadsOptions {
+	pub prefix: Option<String>,
+	pub delimiter: Option<String>,
+	pub key_marker: Option<String>,
+	pub upload_id_marker: Option<String>,
+	/// 单页最多返回的数量, 取值 1~1000, 默认 1000
+	pub max_uploads: Option<u32>,
+}
+
+impl ListMultipartUploadsOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn prefix<T: ToString>(mut self, prefix: T) -> Self {
+		self.prefix = Some(prefix.to_string());
+		self
+	}
+	pub fn delimiter<T: ToString>(mut self, delimiter: T) -> Self {
+		self.delimiter = Some(delimiter.to_string());
+		self
+	}
+	pub fn key_marker<T: ToString>(mut self, key_marker: T) -> Self {
+		self.key_marker = Some(key_marker.to_string());
+		self
+	}
+	pub fn upload_id_marker<T: ToString>(mut self, upload_id_marker: T) -> Self {
+		self.upload_id_marker = Some(upload_id_marker.to_string());
+		self
+	}
+	pub fn max_uploads(mut self, max_uploads: u32) -> Self {
+		self.max_uploads = Some(max_uploads);
+		self
+	}
+}
+
+/// # 列举分片上传事件的结果
+#[derive(Debug, Clone)]
+pub struct ListMultipartUploadsResult {
+	pub bucket: String,
+	pub prefix: String,
+	pub delimiter: String,
+	pub key_marker: String,
+	pub upload_id_marker: String,
+	pub next_key_marker: String,
+	pub next_upload_id_marker: String,
+	pub max_uploads: u32,
+	pub is_truncated: bool,
+	pub uploads: Vec<MultipartUpload>,
+	pub folders: Vec<crate::Folder>,
+}
+
+impl ListMultipartUploadsResult {
+	pub fn new_from_xml_node(node: roxmltree::Node) -> anyhow::Result<Self> {
+		let text = |tag: &str| node.children().find(|n| n.has_tag_name(tag)).and_then(|node| node.text()).unwrap_or("");
+		let mut uploads = Vec::new();
+		for upload_node in node.children().filter(|n| n.has_tag_name("Upload")) {
+			uploads.push(MultipartUpload::new_from_xml_node(upload_node)?);
+		}
+		let mut folders = Vec::new();
+		for folder_node in node.children().filter(|n| n.has_tag_name("CommonPrefixes")) {
+			folders.push(crate::Folder::new_from_xml_node(folder_node)?);
+		}
+		Ok(Self {
+			bucket: text("Bucket").to_string(),
+			prefix: text("Prefix").to_string(),
+			delimiter: text("Delimiter").to_string(),
+			key_marker: text("KeyMarker").to_string(),
+			upload_id_marker: text("UploadIdMarker").to_string(),
+			next_key_marker: text("NextKeyMarker").to_string(),
+			next_upload_id_marker: text("NextUploadIdMarker").to_string(),
+			max_uploads: text("MaxUploads").parse().unwrap_or_default(),
+			is_truncated: text("IsTruncated") == "true",
+			uploads,
+			folders,
+		})
+	}
+}
@@ -0,0 +1,189 @@
+use reqwest::header::HeaderMap;
+use std::collections::BTreeMap;
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+	headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+fn header_string(headers: &HeaderMap, name: &str) -> Option<String> {
+	header_str(headers, name).map(|v| v.to_string())
+}
+
+fn header_parse<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> anyhow::Result<Option<T>>
+where
+	T::Err: std::error::Error + Send + Sync + 'static,
+{
+	match header_str(headers, name) {
+		Some(v) => Ok(Some(v.parse()?)),
+		None => Ok(None),
+	}
+}
+
+fn header_date(headers: &HeaderMap, name: &str) -> anyhow::Result<Option<chrono::DateTime<chrono::Utc>>> {
+	match header_str(headers, name) {
+		Some(v) => Ok(Some(chrono::DateTime::parse_from_rfc2822(v)?.with_timezone(&chrono::Utc))),
+		None => Ok(None),
+	}
+}
+
+fn header_request_id(headers: &HeaderMap) -> String {
+	header_string(headers, "x-oss-request-id").unwrap_or_default()
+}
+
+fn header_etag(headers: &HeaderMap) -> String {
+	header_str(headers, "etag").unwrap_or("").trim_matches('"').to_string()
+}
+
+/// # 对象类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectType {
+	Normal,
+	Appendable,
+	Multipart,
+	Symlink,
+	Other(String),
+}
+
+impl From<&str> for ObjectType {
+	fn from(object_type: &str) -> Self {
+		match object_type {
+			"Normal" => Self::Normal,
+			"Appendable" => Self::Appendable,
+			"Multipart" => Self::Multipart,
+			"Symlink" => Self::Symlink,
+			object_type => Self::Other(object_type.to_string()),
+		}
+	}
+}
+
+/// # 对象元数据
+/// head_object 返回全部元数据, get_object_meta 只返回 ETag, 大小, 最后修改时间等基本信息
+/// ```
+/// # use ali_oss::{ObjectMeta, ObjectType};
+/// let mut headers = reqwest::header::HeaderMap::new();
+/// headers.insert("content-length", "1024".parse().unwrap());
+/// headers.insert("etag", "\"5B3C1A2E053D763E1B002CC607C5A0FE\"".parse().unwrap());
+/// headers.insert("last-modified", "Fri, 24 Feb 2012 06:07:48 GMT".parse().unwrap());
+/// headers.insert("x-oss-object-type", "Appendable".parse().unwrap());
+/// headers.insert("x-oss-next-append-position", "1024".parse().unwrap());
+/// headers.insert("x-oss-meta-author", "ali-oss".parse().unwrap());
+/// let meta = ObjectMeta::new_from_headers(headers).unwrap();
+/// assert_eq!(meta.content_length, 1024);
+/// assert_eq!(meta.etag, "5B3C1A2E053D763E1B002CC607C5A0FE");
+/// assert_eq!(meta.object_type, Some(ObjectType::Appendable));
+/// assert_eq!(meta.metadata.get("author").map(|v| v.as_str()), Some("ali-oss"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ObjectMeta {
+	pub request_id: String,
+	pub content_length: u64,
+	pub content_type: Option<String>,
+	pub etag: String,
+	pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
+	pub object_type: Option<ObjectType>,
+	pub storage_class: Option<crate::StorageClass>,
+	pub crc64: Option<u64>,
+	pub version_id: Option<String>,
+	/// 追加类型对象下一次追加的位置
+	pub next_append_position: Option<u64>,
+	/// 归档对象的解冻状态, 如 `ongoing-request="false", expiry-date="..."`
+	pub restore: Option<String>,
+	pub server_side_encryption: Option<String>,
+	pub server_side_data_encryption: Option<String>,
+	pub server_side_encryption_key_id: Option<String>,
+	/// 用户自定义元数据, key 为去掉 `x-oss-meta-` 前缀后的小写名称
+	pub metadata: BTreeMap<String, String>,
+	pub headers: HeaderMap,
+}
+
+impl ObjectMeta {
+	pub fn new_from_headers(headers: HeaderMap) -> anyhow::Result<Self> {
+		let mut metadata = BTreeMap::new();
+		for (k, v) in headers.iter() {
+			if let Some(key) = k.as_str().strip_prefix("x-oss-meta-") {
+				metadata.insert(key.to_string(), String::from_utf8_lossy(v.as_bytes()).into_owned());
+			}
+		}
+		Ok(Self {
+			request_id: header_request_id(&headers),
+			content_length: header_parse(&headers, "content-length")?.unwrap_or_default(),
+			content_type: header_string(&headers, "content-type"),
+			etag: header_etag(&headers),
+			last_modified: header_date(&headers, "last-modified")?,
+			object_type: header_str(&headers, "x-oss-object-type").map(ObjectType::from),
+			storage_class: header_str(&headers, "x-oss-storage-class").map(crate::StorageClass::from),
+			crc64: header_parse(&headers, "x-oss-hash-crc64ecma")?,
+			version_id: header_string(&headers, "x-oss-version-id"),
+			next_append_position: header_parse(&headers, "x-oss-next-append-position")?,
+			restore: header_string(&headers, "x-oss-restore"),
+			server_side_encryption: header_string(&headers, "x-oss-server-side-encryption"),
+			server_side_data_encryption: header_string(&headers, "x-oss-server-side-data-encryption"),
+			server_side_encryption_key_id: header_string(&headers, "x-oss-server-side-encryption-key-id"),
+			metadata,
+			headers,
+		})
+	}
+}
+
+/// # put_object, put_object_stream 与 append_object 的结果
+#[derive(Debug, Clone)]
+pub struct PutObjectOutput {
+	pub request_id: String,
+	pub etag: String,
+	pub crc64: Option<u64>,
+	pub version_id: Option<String>,
+	/// 仅 append_object 返回
+	pub next_append_position: Option<u64>,
+	/// 设置了上传回调时, 回调服务器返回的内容
+	pub callback_body: Option<String>,
+	/// 对象已写入但回调失败(203 CallbackFailed)时的错误
+	pub callback_error: Option<crate::OssError>,
+	pub headers: HeaderMap,
+}
+
+impl PutObjectOutput {
+	pub fn new_from_headers(headers: HeaderMap) -> anyhow::Result<Self> {
+		Ok(Self {
+			request_id: header_request_id(&headers),
+			etag: header_etag(&headers),
+			crc64: header_parse(&headers, "x-oss-hash-crc64ecma")?,
+			version_id: header_string(&headers, "x-oss-version-id"),
+			next_append_position: header_parse(&headers, "x-oss-next-append-position")?,
+			callback_body: None,
+			callback_error: None,
+			headers,
+		})
+	}
+}
+
+/// # copy_object 的结果
+#[derive(Debug, Clone)]
+pub struct CopyObjectOutput {
+	pub request_id: String,
+	pub etag: String,
+	pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
+	pub crc64: Option<u64>,
+	pub version_id: Option<String>,
+	pub source_version_id: Option<String>,
+	pub headers: HeaderMap,
+}
+
+impl CopyObjectOutput {
+	/// 响应体为 CopyObjectResult, 包含 ETag 与 LastModified
+	pub fn new_from_xml_node(node: roxmltree::Node, headers: HeaderMap) -> anyhow::Result<Self> {
+		let etag = node.descendants().find(|n| n.has_tag_name("ETag")).and_then(|node| node.text()).unwrap_or("").trim_matches('"');
+		let last_modified = match node.descendants().find(|n| n.has_tag_name("LastModified")).and_then(|node| node.text()) {
+			Some(last_modified) => Some(last_modified.parse()?),
+			None => None,
+		};
+		Ok(Self {
+			request_id: header_request_id(&headers),
+			etag: if etag.is_empty() { header_etag(&headers) } else { etag.to_string() },
+			last_modified,
+			crc64: header_parse(&headers, "x-oss-hash-crc64ecma")?,
+			version_id: header_string(&headers, "x-oss-version-id"),
+			source_version_id: header_string(&headers, "x-oss-copy-source-version-id"),
+			headers,
+		})
+	}
+}
@@ -3,15 +3,29 @@ use base64::prelude::*;
 use percent_encoding::percent_decode_str;
 use reqwest::{header, Method, Url};
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone)]
 pub struct OssConfig {
 	pub access_key_id: String,
 	pub access_key_secret: String,
+	/// STS 临时凭证的安全令牌
+	pub security_token: Option<String>,
 	pub bucket_name: String,
 	pub bucket_location: crate::types::BucketLocation,
 	pub path: String,
 	pub is_internal: bool,
+	pub signature_version: crate::types::SignatureVersion,
+	/// V4 签名时额外参与签名的请求头, 如 host
+	pub additional_signed_headers: Vec<String>,
+	/// 自定义访问域名, 为空时使用 `{bucket}.{location}[-internal].aliyuncs.com`
+	pub endpoint: Option<crate::types::Endpoint>,
+	/// 上传与下载时校验 CRC64, 默认开启
+	pub enable_crc64: bool,
+	/// 上传时是否自动设置 Content-Type, 默认开启; 关闭后由 OSS 使用 application/octet-stream
+	pub detect_content_type: bool,
+	/// 自定义扩展名(小写, 不含 `.`)对应的 Content-Type, 优先于内置的 MIME 表
+	pub content_types: BTreeMap<String, String>,
 }
 
 impl OssConfig {
@@ -22,21 +36,45 @@ impl OssConfig {
 		let bucket_location = std::env::var("ALI_OSS_LOCATION")?;
 		let path = std::env::var("ALI_OSS_PATH").unwrap_or("".to_owned());
 		let internal = std::env::var("ALI_OSS_INTERNAL")?;
+		let security_token = std::env::var("ALI_OSS_SECURITY_TOKEN").ok().filter(|token| !token.is_empty());
+		let endpoint = match std::env::var("ALI_OSS_ENDPOINT").ok().filter(|endpoint| !endpoint.is_empty()) {
+			Some(endpoint) => {
+				let addressing_style = match std::env::var("ALI_OSS_ADDRESSING_STYLE").unwrap_or_default().as_str() {
+					"cname" => crate::types::AddressingStyle::Cname,
+					"path" => crate::types::AddressingStyle::PathStyle,
+					_ => crate::types::AddressingStyle::VirtualHosted,
+				};
+				Some(crate::types::Endpoint::parse(&endpoint)?.addressing_style(addressing_style))
+			}
+			None => None,
+		};
 
 		// let bucket = crate::Bucket::new(bucket_name, bucket_location, "".to_owned(), None);
-		let client = Self::new(access_key_id, access_key_secret, bucket_name, bucket_location, path, internal == "true");
+		let mut client = Self::new(access_key_id, access_key_secret, bucket_name, bucket_location, path, internal == "true");
+		client.security_token = security_token;
+		client.endpoint = endpoint;
 		Ok(client)
 	}
 	pub fn new(access_key_id: String, access_key_secret: String, bucket_name: String, bucket_location: String, path: String, is_internal: bool) -> Self {
-		let path = if path.starts_with("/") { path[1..].to_string() } else { path };
+		let path = match path.strip_prefix('/') {
+			Some(path) => path.to_string(),
+			None => path,
+		};
 		let path = if path.ends_with("/") { path[..path.len() - 1].to_string() } else { path };
 		Self {
 			access_key_id: access_key_id.to_string(),
 			access_key_secret: access_key_secret.to_string(),
+			security_token: None,
 			bucket_name: bucket_name.to_string(),
 			bucket_location: crate::types::BucketLocation::new(bucket_location),
 			path,
 			is_internal,
+			signature_version: crate::types::SignatureVersion::default(),
+			additional_signed_headers: Vec::new(),
+			endpoint: None,
+			enable_crc64: true,
+			detect_content_type: true,
+			content_types: BTreeMap::new(),
 		}
 	}
 	/// # 返回 endpoint 对应的链接地址
@@ -68,13 +106,9 @@ impl OssConfig {
 impl OssConfig {
 	pub fn get_object_name<'a>(&self, object_name: &'a str) -> Cow<'a, str> {
 		if self.path.is_empty() {
-			if object_name.starts_with("/") {
-				object_name[1..].into()
-			} else {
-				object_name.into()
-			}
+			object_name.strip_prefix('/').unwrap_or(object_name).into()
 		} else {
-			format!("{}/{}", self.path, if object_name.starts_with("/") { &object_name[1..] } else { object_name }).into()
+			format!("{}/{}", self.path, object_name.strip_prefix('/').unwrap_or(object_name)).into()
 		}
 	}
 	pub fn get_encoded_object_name<'a>(&self, object_name: &'a str) -> Cow<'a, str> {
@@ -87,8 +121,17 @@ impl OssConfig {
 		decode_if_encoded(object_name.as_ref()).into()
 	}
 
+	/// # V4 签名使用的地域, 如 oss-cn-hangzhou 对应 cn-hangzhou
+	pub fn get_region(&self) -> &str {
+		let location = self.bucket_location.as_str();
+		location.strip_prefix("oss-").unwrap_or(location)
+	}
+
 	pub fn get_endpoint_url(&self) -> anyhow::Result<Url> {
-		Self::generate_endpoint_url(&self.bucket_location.as_str(), self.is_internal)
+		match &self.endpoint {
+			Some(endpoint) => endpoint.get_service_url(),
+			None => Self::generate_endpoint_url(self.bucket_location.as_str(), self.is_internal),
+		}
 	}
 
 	pub fn get_endpoint_request(&self, method: Method) -> anyhow::Result<reqwest::Request> {
@@ -99,34 +142,288 @@ impl OssConfig {
 	}
 
 	pub fn get_bucket_url(&self) -> anyhow::Result<Url> {
-		Self::generate_bucket_url(&self.bucket_name, &self.bucket_location.as_str(), self.is_internal)
+		match &self.endpoint {
+			Some(endpoint) => endpoint.get_bucket_url(&self.bucket_name),
+			None => Self::generate_bucket_url(&self.bucket_name, self.bucket_location.as_str(), self.is_internal),
+		}
+	}
+
+	/// # 返回 object 对应的链接地址
+	/// object_name 需要是已经拼接过 path 的完整名称
+	pub fn get_object_url(&self, object_name: &str) -> anyhow::Result<Url> {
+		let mut url = self.get_bucket_url()?;
+		let path = format!("{}{}", url.path(), object_name);
+		url.set_path(&path);
+		Ok(url)
+	}
+
+	/// # 推断上传内容的 Content-Type
+	/// 依次使用自定义的扩展名映射, 内置的 MIME 表, 内容的 magic bytes, 最后为 application/octet-stream;
+	/// 关闭 detect_content_type 时返回 None
+	/// ```
+	/// # use ali_oss::OssConfig;
+	/// let mut oss_config = OssConfig::new("id".to_owned(), "secret".to_owned(), "bucket".to_owned(), "oss-cn-hangzhou".to_owned(), "".to_owned(), false);
+	/// assert_eq!(oss_config.get_content_type(Some("site/app.JS"), None).as_deref(), Some("text/javascript"));
+	/// assert_eq!(oss_config.get_content_type(Some("logo"), Some(b"\x89PNG\r\n\x1a\n")).as_deref(), Some("image/png"));
+	/// assert_eq!(oss_config.get_content_type(Some("data"), Some(b"plain")).as_deref(), Some("application/octet-stream"));
+	/// oss_config.content_types.insert("js".to_owned(), "application/javascript".to_owned());
+	/// assert_eq!(oss_config.get_content_type(Some("app.js"), None).as_deref(), Some("application/javascript"));
+	/// oss_config.detect_content_type = false;
+	/// assert_eq!(oss_config.get_content_type(Some("index.html"), None), None);
+	/// ```
+	pub fn get_content_type(&self, object_name: Option<&str>, body: Option<&[u8]>) -> Option<String> {
+		if !self.detect_content_type {
+			return None;
+		}
+		if let Some(extension) = object_name.and_then(crate::types::mime::extension) {
+			if let Some(content_type) = self.content_types.get(&extension) {
+				return Some(content_type.clone());
+			}
+			if let Some(content_type) = crate::types::mime::from_extension(&extension) {
+				return Some(content_type.to_string());
+			}
+		}
+		if let Some(mime) = body.and_then(infer::get) {
+			return Some(mime.mime_type().to_string());
+		}
+		Some("application/octet-stream".to_string())
 	}
 
 	pub fn get_bucket_request(&self, method: Method, body: Option<bytes::Bytes>) -> anyhow::Result<reqwest::Request> {
 		let url = self.get_bucket_url()?;
+		self.new_request(method, url, None, body)
+	}
+
+	pub fn get_object_request(&self, method: Method, object_name: &str, body: Option<bytes::Bytes>) -> anyhow::Result<reqwest::Request> {
+		let url = self.get_object_url(object_name)?;
+		self.new_request(method, url, Some(object_name), body)
+	}
+
+	fn new_request(&self, method: Method, url: Url, object_name: Option<&str>, body: Option<bytes::Bytes>) -> anyhow::Result<reqwest::Request> {
 		let mut request = reqwest::Request::new(method, url);
 		if let Some(body) = body {
-			match infer::get(&body) {
-				Some(mime) => {
-					request.headers_mut().insert(header::CONTENT_TYPE, mime.mime_type().try_into()?);
-				}
-				None => {
-					request.headers_mut().insert(header::CONTENT_TYPE, "text/plain".try_into()?);
-				}
+			if let Some(content_type) = self.get_content_type(object_name, Some(&body)) {
+				request.headers_mut().insert(header::CONTENT_TYPE, content_type.try_into()?);
 			}
 			// 计算md5
 			request.headers_mut().insert("Content-MD5", {
 				let md5_hash = md5::compute(&body);
 				base64::engine::general_purpose::STANDARD.encode(md5_hash.as_slice()).try_into()?
 			});
-			request.headers_mut().insert(header::CONTENT_LENGTH, body.len().try_into()?);
+			request.headers_mut().insert(header::CONTENT_LENGTH, body.len().into());
 			*request.body_mut() = Some(reqwest::Body::from(body));
 		}
-		// request.headers_mut().insert(header::CONTENT_TYPE, "text/plain".try_into()?);
 		Ok(request)
 	}
 
-	pub(crate) fn sign_header_request(&self, request: &mut reqwest::Request) -> anyhow::Result<()> {
+	/// # 使用 OssConfig 中的 access_key 生成的凭证
+	pub fn get_credentials(&self) -> crate::Credentials {
+		crate::Credentials::new(self.access_key_id.clone(), self.access_key_secret.clone(), self.security_token.clone())
+	}
+
+	pub(crate) fn sign_header_request(&self, request: &mut reqwest::Request, credentials: &crate::Credentials) -> anyhow::Result<()> {
+		match &credentials.security_token {
+			Some(security_token) => {
+				request.headers_mut().insert("x-oss-security-token", security_token.try_into()?);
+			}
+			None => {
+				request.headers_mut().remove("x-oss-security-token");
+			}
+		}
+		match self.signature_version {
+			crate::types::SignatureVersion::V1 => self.sign_header_request_v1(request, credentials),
+			crate::types::SignatureVersion::V4 => self.sign_header_request_v4(request, credentials),
+		}
+	}
+	/// # 生成预签名 URL
+	/// object_name 需要是已经拼接过 path 的完整名称
+	pub(crate) fn presign_object_url(&self, object_name: &str, expires_duration: std::time::Duration, options: &crate::PresignOptions, credentials: &crate::Credentials) -> anyhow::Result<Url> {
+		let oss_config = match &options.endpoint {
+			Some(endpoint) => Cow::Owned(Self {
+				endpoint: Some(endpoint.clone()),
+				..self.clone()
+			}),
+			None => Cow::Borrowed(self),
+		};
+		let object_name = decode_if_encoded(object_name);
+		let mut url = oss_config.get_object_url(&object_name)?;
+		if let Some(scheme) = &options.scheme {
+			url.set_scheme(scheme).map_err(|_| anyhow::anyhow!("invalid scheme {}", scheme))?;
+		}
+		let resource_path = oss_config.get_resource_path(&reqwest::Request::new(options.method.clone(), url.clone()))?.unwrap_or("/".to_string());
+		let security_token = options.security_token.as_ref().or(credentials.security_token.as_ref());
+		let oss_headers: BTreeMap<String, String> = options.headers.iter().filter(|(k, _)| k.starts_with("x-oss-")).map(|(k, v)| (k.clone(), v.clone())).collect();
+
+		let mut signed_query = options.query.clone();
+		let signature_query = match self.signature_version {
+			crate::types::SignatureVersion::V1 => {
+				let expires_time = chrono::Utc::now() + chrono::Duration::from_std(expires_duration)?;
+				if let Some(security_token) = security_token {
+					// 使用 STS 临时凭证时 security-token 需要参与签名
+					signed_query.insert("security-token".to_string(), security_token.clone());
+				}
+				let query: Vec<(String, String)> = signed_query.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+				let signature_string = crate::types::ParamSignature::new(
+					options.method.clone(),
+					options.content_md5.clone(),
+					options.content_type.clone(),
+					expires_time,
+					crate::types::CanonicalizedHeaders::new(if oss_headers.is_empty() { None } else { Some(oss_headers) }),
+					crate::types::CanonicalizedResource::new_with_sub_resources(&resource_path, &query),
+				)
+				.get_signature_string(credentials);
+				vec![
+					("OSSAccessKeyId".to_string(), credentials.access_key_id.clone()),
+					("Expires".to_string(), expires_time.timestamp().to_string()),
+					("Signature".to_string(), signature_string),
+				]
+			}
+			crate::types::SignatureVersion::V4 => {
+				if let Some(security_token) = security_token {
+					signed_query.insert("x-oss-security-token".to_string(), security_token.clone());
+				}
+				let mut headers = oss_headers;
+				if let Some(content_type) = &options.content_type {
+					headers.insert("content-type".to_string(), content_type.clone());
+				}
+				if let Some(content_md5) = &options.content_md5 {
+					headers.insert("content-md5".to_string(), content_md5.clone());
+				}
+				if self.additional_signed_headers.iter().any(|h| h == "host") {
+					let host = url.host_str().ok_or(anyhow::anyhow!("host not found"))?;
+					let host = match url.port() {
+						Some(port) => format!("{}:{}", host, port),
+						None => host.to_string(),
+					};
+					headers.insert("host".to_string(), host);
+				}
+				let param_signature = crate::types::ParamSignatureV4::new(
+					options.method.clone(),
+					resource_path,
+					signed_query,
+					headers,
+					self.additional_signed_headers.clone(),
+					chrono::Utc::now(),
+					expires_duration,
+					self.get_region().to_string(),
+					crate::types::signature::V4_PRODUCT.to_string(),
+				);
+				let signature_string = param_signature.get_signature_string(credentials);
+				signed_query = param_signature.get_signed_query(&credentials.access_key_id);
+				vec![("x-oss-signature".to_string(), signature_string)]
+			}
+		};
+		for (k, v) in signed_query.iter().chain(signature_query.iter().map(|(k, v)| (k, v))) {
+			if v.is_empty() {
+				url.query_pairs_mut().append_key_only(k);
+			} else {
+				url.query_pairs_mut().append_pair(k, v);
+			}
+		}
+		Ok(url)
+	}
+
+	/// # 生成 PostObject 表单字段
+	/// Policy 使用 V1(HMAC-SHA1) 或 V4(OSS4-HMAC-SHA256) 签名, STS 令牌会加入条件与表单
+	pub(crate) fn sign_post_policy(&self, policy: &crate::PostPolicy, credentials: &crate::Credentials) -> anyhow::Result<crate::PostObjectForm> {
+		let now = chrono::Utc::now();
+		let expiration = now + chrono::Duration::from_std(policy.expires)?;
+		let mut fields = BTreeMap::new();
+		let mut conditions = vec![serde_json::json!({ "bucket": self.bucket_name })];
+		if let Some(key_prefix) = &policy.key_prefix {
+			conditions.push(serde_json::json!(["starts-with", "$key", self.get_object_name(key_prefix)]));
+		}
+		if let Some(key) = &policy.key {
+			let key = self.get_object_name(key).into_owned();
+			conditions.push(serde_json::json!(["eq", "$key", key]));
+			fields.insert("key".to_string(), key);
+		}
+		if let Some((min, max)) = policy.content_length_range {
+			conditions.push(serde_json::json!(["content-length-range", min, max]));
+		}
+		if let Some(content_type) = &policy.content_type {
+			conditions.push(serde_json::json!(["eq", "$Content-Type", content_type]));
+			fields.insert("Content-Type".to_string(), content_type.clone());
+		}
+		if let Some(content_type_prefix) = &policy.content_type_prefix {
+			conditions.push(serde_json::json!(["starts-with", "$Content-Type", content_type_prefix]));
+		}
+		if let Some(success_action_status) = policy.success_action_status {
+			conditions.push(serde_json::json!({ "success_action_status": success_action_status.to_string() }));
+			fields.insert("success_action_status".to_string(), success_action_status.to_string());
+		}
+		if let Some(callback) = &policy.callback {
+			conditions.push(serde_json::json!({ "callback": callback }));
+			fields.insert("callback".to_string(), callback.clone());
+		}
+		if let Some(security_token) = &credentials.security_token {
+			conditions.push(serde_json::json!({ "x-oss-security-token": security_token }));
+			fields.insert("x-oss-security-token".to_string(), security_token.clone());
+		}
+		let v4_fields = match self.signature_version {
+			crate::types::SignatureVersion::V1 => Vec::new(),
+			crate::types::SignatureVersion::V4 => {
+				let date = now.format("%Y%m%d").to_string();
+				vec![
+					("x-oss-signature-version".to_string(), crate::types::signature::V4_ALGORITHM.to_string()),
+					(
+						"x-oss-credential".to_string(),
+						format!("{}/{}/{}/{}/{}", credentials.access_key_id, date, self.get_region(), crate::types::signature::V4_PRODUCT, crate::types::signature::V4_REQUEST),
+					),
+					("x-oss-date".to_string(), now.format("%Y%m%dT%H%M%SZ").to_string()),
+				]
+			}
+		};
+		for (k, v) in v4_fields {
+			conditions.push(serde_json::json!({ k.as_str(): v }));
+			fields.insert(k, v);
+		}
+		conditions.extend(policy.conditions.iter().cloned());
+
+		let policy_document = serde_json::json!({
+			"expiration": expiration.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+			"conditions": conditions,
+		});
+		let encoded_policy = BASE64_STANDARD.encode(policy_document.to_string());
+		match self.signature_version {
+			crate::types::SignatureVersion::V1 => {
+				let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, credentials.access_key_secret.as_bytes());
+				let signature = BASE64_STANDARD.encode(ring::hmac::sign(&key, encoded_policy.as_bytes()).as_ref());
+				fields.insert("OSSAccessKeyId".to_string(), credentials.access_key_id.clone());
+				fields.insert("Signature".to_string(), signature);
+			}
+			crate::types::SignatureVersion::V4 => {
+				let signing_key = crate::types::signature::v4_signing_key(&credentials.access_key_secret, &now.format("%Y%m%d").to_string(), self.get_region(), crate::types::signature::V4_PRODUCT);
+				let signature = crate::types::signature::hex_encode(&crate::types::signature::hmac_sha256(&signing_key, encoded_policy.as_bytes()));
+				fields.insert("x-oss-signature".to_string(), signature);
+			}
+		}
+		fields.insert("policy".to_string(), encoded_policy);
+		Ok(crate::PostObjectForm {
+			url: self.get_bucket_url()?.to_string(),
+			fields,
+		})
+	}
+
+	/// # 请求对应的 `/{bucket}/{object}`
+	/// 返回 None 表示请求不属于任何 bucket(如 list_buckets)
+	fn get_resource_path(&self, request: &reqwest::Request) -> anyhow::Result<Option<String>> {
+		let path = decode_if_encoded(request.url().path()); // decode_if_encoded 解决路径带中文问题
+		match &self.endpoint {
+			Some(endpoint) => Ok(endpoint.get_resource_path(&self.bucket_name, request.url(), &path)),
+			None => {
+				// bucket 名称可能以地域开头(如 oss-cn-hangzhou-logs), 必须与服务域名完全一致
+				let host = request.url().host_str().ok_or(anyhow::anyhow!("host not found"))?;
+				let service_url = Self::generate_endpoint_url(self.bucket_location.as_str(), self.is_internal)?;
+				if Some(host) == service_url.host_str() {
+					Ok(None)
+				} else {
+					Ok(Some(format!("/{}{}", self.bucket_name, path)))
+				}
+			}
+		}
+	}
+	fn sign_header_request_v1(&self, request: &mut reqwest::Request, credentials: &crate::Credentials) -> anyhow::Result<()> {
 		let content_md5 = {
 			let content_md5 = request.headers().get("Content-MD5");
 			if let Some(content_md5) = content_md5 {
@@ -143,35 +440,55 @@ impl OssConfig {
 				None
 			}
 		};
-		let canonicalized_oss_headers: crate::types::CanonicalizedHeaders = (&*request).into();
-		let canonicalized_resource = {
-			let host = request.url().host_str().ok_or(anyhow::anyhow!("host not found"))?;
-			if host.starts_with(self.bucket_location.as_str()) {
-				crate::types::CanonicalizedResource::default()
-			} else {
-				let path = decode_if_encoded(request.url().path()); // decode_if_encoded 解决路径带中文问题
-				let query = request.url().query();
-				let mut resource = format!("/{}{}", self.bucket_name, path);
-				if let Some(query) = query {
-					if let Some(first_query) = query.split('&').next() {
-						if !first_query.contains("=") {
-							resource.push_str(&format!("?{}", query));
-						}
-					}
-				}
-				crate::types::CanonicalizedResource::new(resource)
+		let canonicalized_oss_headers = crate::types::CanonicalizedHeaders::try_from(&*request)?;
+		let canonicalized_resource = match self.get_resource_path(request)? {
+			Some(resource_path) => {
+				let query: Vec<(String, String)> = request.url().query_pairs().map(|(k, v)| (k.into_owned(), v.into_owned())).collect();
+				crate::types::CanonicalizedResource::new_with_sub_resources(&resource_path, &query)
 			}
+			None => crate::types::CanonicalizedResource::default(),
 		};
 		let header_signature = crate::types::HeaderSignature::new(request.method().clone(), content_md5, content_type, chrono::Utc::now(), canonicalized_oss_headers, canonicalized_resource);
-		let signatured_string = header_signature.get_signature_string(self);
-		let authorization = format!("OSS {}:{}", self.access_key_id, signatured_string);
+		let signatured_string = header_signature.get_signature_string(credentials);
+		let authorization = format!("OSS {}:{}", credentials.access_key_id, signatured_string);
 		request.headers_mut().insert("Authorization", authorization.try_into()?);
 		request.headers_mut().insert("Date", header_signature.get_date_string().try_into()?);
 		Ok(())
 	}
-	pub(crate) fn get_request_builder(&self, request: reqwest::Request) -> anyhow::Result<reqwest::RequestBuilder> {
-		let req_client = reqwest::Client::new();
-		Ok(reqwest::RequestBuilder::from_parts(req_client, request))
+	fn sign_header_request_v4(&self, request: &mut reqwest::Request, credentials: &crate::Credentials) -> anyhow::Result<()> {
+		let canonical_uri = self.get_resource_path(request)?.unwrap_or("/".to_string());
+		let query: BTreeMap<String, String> = request.url().query_pairs().map(|(k, v)| (k.into_owned(), v.into_owned())).collect();
+		let mut header_signature = crate::types::HeaderSignatureV4::new(
+			request.method().clone(),
+			canonical_uri,
+			query,
+			BTreeMap::new(),
+			self.additional_signed_headers.clone(),
+			chrono::Utc::now(),
+			self.get_region().to_string(),
+			crate::types::signature::V4_PRODUCT.to_string(),
+		);
+		request.headers_mut().insert("x-oss-date", header_signature.get_date_string().try_into()?);
+		request.headers_mut().insert("x-oss-content-sha256", crate::types::signature::V4_UNSIGNED_PAYLOAD.try_into()?);
+		for (k, v) in request.headers() {
+			let key = k.as_str();
+			if key.starts_with("x-oss-") || key == "content-type" || key == "content-md5" || header_signature.additional_headers.iter().any(|h| h == key) {
+				header_signature.headers.insert(key.to_string(), std::str::from_utf8(v.as_bytes())?.to_string());
+			}
+		}
+		if header_signature.additional_headers.iter().any(|h| h == "host") {
+			let url = request.url();
+			let host = url.host_str().ok_or(anyhow::anyhow!("host not found"))?;
+			let host = match url.port() {
+				Some(port) => format!("{}:{}", host, port),
+				None => host.to_string(),
+			};
+			header_signature.headers.insert("host".to_string(), host);
+		}
+		let signatured_string = header_signature.get_signature_string(credentials);
+		let authorization = header_signature.get_authorization(&credentials.access_key_id, &signatured_string);
+		request.headers_mut().insert("Authorization", authorization.try_into()?);
+		Ok(())
 	}
 }
 
@@ -1,17 +1,222 @@
-#[derive(Debug)]
+use base64::prelude::*;
+
+/// # OSS 错误码
+/// https://help.aliyun.com/zh/oss/support/http-status-code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OssErrorCode {
+	AccessDenied,
+	BucketAlreadyExists,
+	BucketNotEmpty,
+	CallbackFailed,
+	EntityTooLarge,
+	EntityTooSmall,
+	FileAlreadyExists,
+	InternalError,
+	InvalidAccessKeyId,
+	InvalidArgument,
+	InvalidBucketName,
+	InvalidDigest,
+	InvalidObjectName,
+	InvalidPart,
+	InvalidPartOrder,
+	InvalidRange,
+	MalformedXML,
+	MissingContentLength,
+	NoSuchBucket,
+	NoSuchKey,
+	NoSuchUpload,
+	NotImplemented,
+	NotModified,
+	ObjectNotAppendable,
+	PositionNotEqualToLength,
+	PreconditionFailed,
+	RequestTimeTooSkewed,
+	RequestTimeout,
+	SecurityTokenExpired,
+	SignatureDoesNotMatch,
+	TooManyBuckets,
+	Other(String),
+}
+
+impl OssErrorCode {
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::AccessDenied => "AccessDenied",
+			Self::BucketAlreadyExists => "BucketAlreadyExists",
+			Self::BucketNotEmpty => "BucketNotEmpty",
+			Self::CallbackFailed => "CallbackFailed",
+			Self::EntityTooLarge => "EntityTooLarge",
+			Self::EntityTooSmall => "EntityTooSmall",
+			Self::FileAlreadyExists => "FileAlreadyExists",
+			Self::InternalError => "InternalError",
+			Self::InvalidAccessKeyId => "InvalidAccessKeyId",
+			Self::InvalidArgument => "InvalidArgument",
+			Self::InvalidBucketName => "InvalidBucketName",
+			Self::InvalidDigest => "InvalidDigest",
+			Self::InvalidObjectName => "InvalidObjectName",
+			Self::InvalidPart => "InvalidPart",
+			Self::InvalidPartOrder => "InvalidPartOrder",
+			Self::InvalidRange => "InvalidRange",
+			Self::MalformedXML => "MalformedXML",
+			Self::MissingContentLength => "MissingContentLength",
+			Self::NoSuchBucket => "NoSuchBucket",
+			Self::NoSuchKey => "NoSuchKey",
+			Self::NoSuchUpload => "NoSuchUpload",
+			Self::NotImplemented => "NotImplemented",
+			Self::NotModified => "NotModified",
+			Self::ObjectNotAppendable => "ObjectNotAppendable",
+			Self::PositionNotEqualToLength => "PositionNotEqualToLength",
+			Self::PreconditionFailed => "PreconditionFailed",
+			Self::RequestTimeTooSkewed => "RequestTimeTooSkewed",
+			Self::RequestTimeout => "RequestTimeout",
+			Self::SecurityTokenExpired => "SecurityTokenExpired",
+			Self::SignatureDoesNotMatch => "SignatureDoesNotMatch",
+			Self::TooManyBuckets => "TooManyBuckets",
+			Self::Other(code) => code.as_str(),
+		}
+	}
+}
+
+impl From<&str> for OssErrorCode {
+	fn from(code: &str) -> Self {
+		match code {
+			"AccessDenied" => Self::AccessDenied,
+			"BucketAlreadyExists" => Self::BucketAlreadyExists,
+			"BucketNotEmpty" => Self::BucketNotEmpty,
+			"CallbackFailed" => Self::CallbackFailed,
+			"EntityTooLarge" => Self::EntityTooLarge,
+			"EntityTooSmall" => Self::EntityTooSmall,
+			"FileAlreadyExists" => Self::FileAlreadyExists,
+			"InternalError" => Self::InternalError,
+			"InvalidAccessKeyId" => Self::InvalidAccessKeyId,
+			"InvalidArgument" => Self::InvalidArgument,
+			"InvalidBucketName" => Self::InvalidBucketName,
+			"InvalidDigest" => Self::InvalidDigest,
+			"InvalidObjectName" => Self::InvalidObjectName,
+			"InvalidPart" => Self::InvalidPart,
+			"InvalidPartOrder" => Self::InvalidPartOrder,
+			"InvalidRange" => Self::InvalidRange,
+			"MalformedXML" => Self::MalformedXML,
+			"MissingContentLength" => Self::MissingContentLength,
+			"NoSuchBucket" => Self::NoSuchBucket,
+			"NoSuchKey" => Self::NoSuchKey,
+			"NoSuchUpload" => Self::NoSuchUpload,
+			"NotImplemented" => Self::NotImplemented,
+			"NotModified" => Self::NotModified,
+			"ObjectNotAppendable" => Self::ObjectNotAppendable,
+			"PositionNotEqualToLength" => Self::PositionNotEqualToLength,
+			"PreconditionFailed" => Self::PreconditionFailed,
+			"RequestTimeTooSkewed" => Self::RequestTimeTooSkewed,
+			"RequestTimeout" => Self::RequestTimeout,
+			"SecurityTokenExpired" => Self::SecurityTokenExpired,
+			"SignatureDoesNotMatch" => Self::SignatureDoesNotMatch,
+			"TooManyBuckets" => Self::TooManyBuckets,
+			code => Self::Other(code.to_string()),
+		}
+	}
+}
+
+impl std::fmt::Display for OssErrorCode {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// # OSS 服务端返回的错误
+/// 所有 `Client` 方法在请求失败时返回的 `anyhow::Error` 均可 `downcast_ref::<OssError>()`
+#[derive(Debug, Clone)]
 pub struct OssError {
-	code: String,
-	message: String,
-	xml_string: String,
+	pub status: reqwest::StatusCode,
+	pub code: OssErrorCode,
+	pub message: String,
+	pub request_id: String,
+	pub host_id: String,
+	pub ec: String,
+	pub xml_string: String,
 }
 
 impl OssError {
-	pub fn new_from_xml_string(xml_string: String) -> anyhow::Result<Self> {
+	/// # 解析错误响应体
+	/// ```
+	/// # use ali_oss::{OssError, OssErrorCode};
+	/// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+	/// <Error>
+	///   <Code>NoSuchKey</Code>
+	///   <Message>The specified key does not exist.</Message>
+	///   <RequestId>5C3D9175B6FC201293AD****</RequestId>
+	///   <HostId>examplebucket.oss-cn-hangzhou.aliyuncs.com</HostId>
+	///   <EC>0026-00000001</EC>
+	/// </Error>"#;
+	/// let error = OssError::new_from_xml_string(reqwest::StatusCode::NOT_FOUND, xml.to_string()).unwrap();
+	/// assert_eq!(error.code, OssErrorCode::NoSuchKey);
+	/// assert_eq!(error.ec, "0026-00000001");
+	/// ```
+	pub fn new_from_xml_string(status: reqwest::StatusCode, xml_string: String) -> anyhow::Result<Self> {
 		let doc: roxmltree::Document = roxmltree::Document::parse(&xml_string)?;
+		let node = doc.descendants().find(|n| n.has_tag_name("Error")).ok_or_else(|| anyhow::anyhow!("Error node not found"))?;
+		let code = node.descendants().find(|n| n.has_tag_name("Code")).and_then(|node| node.text()).unwrap_or("");
+		let message = node.descendants().find(|n| n.has_tag_name("Message")).and_then(|node| node.text()).unwrap_or("");
+		let request_id = node.descendants().find(|n| n.has_tag_name("RequestId")).and_then(|node| node.text()).unwrap_or("");
+		let host_id = node.descendants().find(|n| n.has_tag_name("HostId")).and_then(|node| node.text()).unwrap_or("");
+		let ec = node.descendants().find(|n| n.has_tag_name("EC")).and_then(|node| node.text()).unwrap_or("");
 		Ok(Self {
-			code: "".to_string(),
-			message: "".to_string(),
-			xml_string,
+			status,
+			code: code.into(),
+			message: message.to_string(),
+			request_id: request_id.to_string(),
+			host_id: host_id.to_string(),
+			ec: ec.to_string(),
+			xml_string: xml_string.to_string(),
 		})
 	}
+	/// # 解析 HEAD 请求返回的 x-oss-err 头
+	/// HEAD 请求没有响应体, 错误信息以 base64 编码放在 x-oss-err 中
+	pub fn new_from_encoded_header(status: reqwest::StatusCode, encoded_error: &str) -> anyhow::Result<Self> {
+		let xml_string = String::from_utf8(BASE64_STANDARD.decode(encoded_error.as_bytes())?)?;
+		Self::new_from_xml_string(status, xml_string)
+	}
+	/// # 从失败的响应中构造错误
+	/// 响应体不是 OSS 错误格式时(如网关返回的 html), 保留原始内容并以 http 状态作为错误信息
+	pub async fn from_response(response: reqwest::Response) -> anyhow::Result<Self> {
+		let status = response.status();
+		let header_request_id = response.headers().get("x-oss-request-id").and_then(|v| v.to_str().ok()).unwrap_or("").to_owned();
+		let encoded_error = response.headers().get("x-oss-err").and_then(|v| v.to_str().ok()).map(|v| v.to_owned());
+		let body = response.text().await?;
+		let parsed = if !body.is_empty() {
+			Self::new_from_xml_string(status, body.clone()).ok()
+		} else if let Some(encoded_error) = encoded_error {
+			Self::new_from_encoded_header(status, &encoded_error).ok()
+		} else {
+			None
+		};
+		let mut error = parsed.unwrap_or_else(|| Self {
+			status,
+			// 304 与 HEAD 请求的 412 没有响应体, 以状态描述作为错误码(NotModified, PreconditionFailed)
+			code: OssErrorCode::from(status.canonical_reason().unwrap_or("").replace(' ', "").as_str()),
+			message: if body.is_empty() { status.to_string() } else { body.clone() },
+			request_id: "".to_string(),
+			host_id: "".to_string(),
+			ec: "".to_string(),
+			xml_string: body,
+		});
+		if error.request_id.is_empty() {
+			error.request_id = header_request_id;
+		}
+		Ok(error)
+	}
 }
+
+impl std::fmt::Display for OssError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} ({}): {}", self.code, self.status.as_u16(), self.message)?;
+		if !self.request_id.is_empty() {
+			write!(f, ", request id: {}", self.request_id)?;
+		}
+		if !self.ec.is_empty() {
+			write!(f, ", ec: {}", self.ec)?;
+		}
+		Ok(())
+	}
+}
+
+impl std::error::Error for OssError {}
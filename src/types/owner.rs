@@ -0,0 +1,23 @@
+/// # 拥有者
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Owner {
+	pub id: String,
+	pub display_name: String,
+}
+
+impl Owner {
+	pub fn new<T: ToString>(id: T, display_name: T) -> Self {
+		Self {
+			id: id.to_string(),
+			display_name: display_name.to_string(),
+		}
+	}
+}
+
+impl Owner {
+	pub fn new_from_xml_node(node: roxmltree::Node) -> anyhow::Result<Self> {
+		let id = node.descendants().find(|n| n.has_tag_name("ID")).and_then(|node| node.text()).unwrap_or("");
+		let display_name = node.descendants().find(|n| n.has_tag_name("DisplayName")).and_then(|node| node.text()).unwrap_or("");
+		Ok(Self::new(id, display_name))
+	}
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+/// # PostObject 表单上传的 Policy
+/// ```
+/// # use ali_oss::PostPolicy;
+/// let policy = PostPolicy::new().expires(std::time::Duration::from_secs(600)).key_prefix("uploads/").content_length_range(1, 10 * 1024 * 1024).content_type_prefix("image/").success_action_status(201);
+/// assert_eq!(policy.content_length_range, Some((1, 10 * 1024 * 1024)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PostPolicy {
+	/// Policy 的有效期, 默认为 1 小时
+	pub expires: std::time::Duration,
+	/// 对象名称必须以此开头, 会加上客户端的 path 前缀
+	pub key_prefix: Option<String>,
+	/// 对象名称必须等于此值, 会加上客户端的 path 前缀
+	pub key: Option<String>,
+	/// 文件大小的范围(字节), 包含两端
+	pub content_length_range: Option<(u64, u64)>,
+	pub content_type: Option<String>,
+	/// Content-Type 必须以此开头, 如 `image/`
+	pub content_type_prefix: Option<String>,
+	/// 上传成功后返回的状态码, 200/201/204
+	pub success_action_status: Option<u16>,
+	/// base64 编码后的回调参数, 见 `CallbackOptions::encode_callback`
+	pub callback: Option<String>,
+	/// 其它条件, 如 `["starts-with", "$x-oss-meta-author", ""]`
+	pub conditions: Vec<serde_json::Value>,
+}
+
+impl Default for PostPolicy {
+	fn default() -> Self {
+		Self {
+			expires: std::time::Duration::from_secs(3600),
+			key_prefix: None,
+			key: None,
+			content_length_range: None,
+			content_type: None,
+			content_type_prefix: None,
+			success_action_status: None,
+			callback: None,
+			conditions: Vec::new(),
+		}
+	}
+}
+
+impl PostPolicy {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn expires(mut self, expires: std::time::Duration) -> Self {
+		self.expires = expires;
+		self
+	}
+	pub fn key_prefix<T: ToString>(mut self, key_prefix: T) -> Self {
+		self.key_prefix = Some(key_prefix.to_string());
+		self
+	}
+	pub fn key<T: ToString>(mut self, key: T) -> Self {
+		self.key = Some(key.to_string());
+		self
+	}
+	pub fn content_length_range(mut self, min: u64, max: u64) -> Self {
+		self.content_length_range = Some((min, max));
+		self
+	}
+	pub fn content_type<T: ToString>(mut self, content_type: T) -> Self {
+		self.content_type = Some(content_type.to_string());
+		self
+	}
+	pub fn content_type_prefix<T: ToString>(mut self, content_type_prefix: T) -> Self {
+		self.content_type_prefix = Some(content_type_prefix.to_string());
+		self
+	}
+	pub fn success_action_status(mut self, success_action_status: u16) -> Self {
+		self.success_action_status = Some(success_action_status);
+		self
+	}
+	pub fn callback<T: ToString>(mut self, callback: T) -> Self {
+		self.callback = Some(callback.to_string());
+		self
+	}
+	pub fn condition(mut self, condition: serde_json::Value) -> Self {
+		self.conditions.push(condition);
+		self
+	}
+}
+
+/// # PostObject 表单
+/// 前端以 multipart/form-data 向 url 提交 fields, 再加上 key(未指定 `PostPolicy::key` 时)与 file 字段, file 必须是最后一个字段
+#[derive(Debug, Clone)]
+pub struct PostObjectForm {
+	pub url: String,
+	pub fields: BTreeMap<String, String>,
+}
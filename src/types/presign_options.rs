@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+/// # 预签名 URL 参数
+/// 签名包含 method, Content-Type, Content-MD5 与 `x-oss-*` 请求头, 使用 URL 时需要带上相同的请求头
+/// ```
+/// # use ali_oss::PresignOptions;
+/// let options = PresignOptions::new().method(reqwest::Method::PUT).content_type("text/html").header("x-oss-object-acl", "private");
+/// let options = PresignOptions::new().query("versionId", "CAEQARiBgID8rumR2hYiIGUyOTAyZGY2MzU5MjQ5ZjlhYzQzZjNlYTAyZDE3****").response_content_disposition("attachment; filename=\"1.txt\"");
+/// assert_eq!(options.query.get("response-content-disposition").map(|v| v.as_str()), Some("attachment; filename=\"1.txt\""));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PresignOptions {
+	pub method: reqwest::Method,
+	pub content_type: Option<String>,
+	pub content_md5: Option<String>,
+	/// 参与签名的 `x-oss-*` 请求头, key 为小写
+	pub headers: BTreeMap<String, String>,
+	/// 子资源与查询参数, 如 versionId, x-oss-process, response-content-type; 值为空时只有 key
+	pub query: BTreeMap<String, String>,
+	/// 自定义访问域名, 为空时使用客户端的域名
+	pub endpoint: Option<crate::Endpoint>,
+	/// URL 的 scheme, 如 http
+	pub scheme: Option<String>,
+	/// STS 临时凭证的安全令牌, 为空时使用客户端凭证中的令牌
+	pub security_token: Option<String>,
+}
+
+impl Default for PresignOptions {
+	fn default() -> Self {
+		Self {
+			method: reqwest::Method::GET,
+			content_type: None,
+			content_md5: None,
+			headers: BTreeMap::new(),
+			query: BTreeMap::new(),
+			endpoint: None,
+			scheme: None,
+			security_token: None,
+		}
+	}
+}
+
+impl PresignOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn method(mut self, method: reqwest::Method) -> Self {
+		self.method = method;
+		self
+	}
+	pub fn content_type<T: ToString>(mut self, content_type: T) -> Self {
+		self.content_type = Some(content_type.to_string());
+		self
+	}
+	pub fn content_md5<T: ToString>(mut self, content_md5: T) -> Self {
+		self.content_md5 = Some(content_md5.to_string());
+		self
+	}
+	pub fn header<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
+		self.headers.insert(key.to_string().to_lowercase(), value.to_string());
+		self
+	}
+	pub fn query<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
+		self.query.insert(key.to_string(), value.to_string());
+		self
+	}
+	pub fn response_content_type<T: ToString>(self, content_type: T) -> Self {
+		self.query("response-content-type", content_type)
+	}
+	pub fn response_content_disposition<T: ToString>(self, content_disposition: T) -> Self {
+		self.query("response-content-disposition", content_disposition)
+	}
+	pub fn response_content_encoding<T: ToString>(self, content_encoding: T) -> Self {
+		self.query("response-content-encoding", content_encoding)
+	}
+	pub fn response_content_language<T: ToString>(self, content_language: T) -> Self {
+		self.query("response-content-language", content_language)
+	}
+	pub fn response_cache_control<T: ToString>(self, cache_control: T) -> Self {
+		self.query("response-cache-control", cache_control)
+	}
+	pub fn response_expires(self, expires: chrono::DateTime<chrono::Utc>) -> Self {
+		self.query("response-expires", expires.format("%a, %d %b %Y %T GMT"))
+	}
+	pub fn endpoint(mut self, endpoint: crate::Endpoint) -> Self {
+		self.endpoint = Some(endpoint);
+		self
+	}
+	pub fn scheme<T: ToString>(mut self, scheme: T) -> Self {
+		self.scheme = Some(scheme.to_string());
+		self
+	}
+	pub fn security_token<T: ToString>(mut self, security_token: T) -> Self {
+		self.security_token = Some(security_token.to_string());
+		self
+	}
+}
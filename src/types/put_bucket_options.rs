@@ -0,0 +1,95 @@
+/// # 数据容灾类型
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataRedundancyType {
+	/// 本地冗余存储
+	LRS,
+	/// 同城冗余存储
+	ZRS,
+	Other(String),
+}
+
+impl DataRedundancyType {
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::LRS => "LRS",
+			Self::ZRS => "ZRS",
+			Self::Other(data_redundancy_type) => data_redundancy_type.as_str(),
+		}
+	}
+}
+
+impl From<&str> for DataRedundancyType {
+	fn from(data_redundancy_type: &str) -> Self {
+		match data_redundancy_type {
+			"LRS" => Self::LRS,
+			"ZRS" => Self::ZRS,
+			data_redundancy_type => Self::Other(data_redundancy_type.to_string()),
+		}
+	}
+}
+
+/// # 创建 bucket 的参数
+/// ```
+/// # use ali_oss::{Acl, DataRedundancyType, PutBucketOptions, StorageClass};
+/// let options = PutBucketOptions::new().acl(Acl::Private).storage_class(StorageClass::IA).data_redundancy_type(DataRedundancyType::ZRS);
+/// assert!(options.get_xml_body().unwrap().contains("<DataRedundancyType>ZRS</DataRedundancyType>"));
+/// assert!(PutBucketOptions::new().acl(Acl::Private).get_xml_body().is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PutBucketOptions {
+	/// 对应 `x-oss-acl`, bucket 不支持 `Acl::Default`
+	pub acl: Option<crate::Acl>,
+	pub storage_class: Option<crate::StorageClass>,
+	pub data_redundancy_type: Option<DataRedundancyType>,
+	/// 对应 `x-oss-resource-group-id`, 为空时属于默认资源组
+	pub resource_group_id: Option<String>,
+}
+
+impl PutBucketOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn acl(mut self, acl: crate::Acl) -> Self {
+		self.acl = Some(acl);
+		self
+	}
+	pub fn storage_class(mut self, storage_class: crate::StorageClass) -> Self {
+		self.storage_class = Some(storage_class);
+		self
+	}
+	pub fn data_redundancy_type(mut self, data_redundancy_type: DataRedundancyType) -> Self {
+		self.data_redundancy_type = Some(data_redundancy_type);
+		self
+	}
+	pub fn resource_group_id<T: ToString>(mut self, resource_group_id: T) -> Self {
+		self.resource_group_id = Some(resource_group_id.to_string());
+		self
+	}
+
+	/// # CreateBucketConfiguration 请求体
+	/// 未设置存储类型与容灾类型时返回 None
+	pub fn get_xml_body(&self) -> Option<String> {
+		if self.storage_class.is_none() && self.data_redundancy_type.is_none() {
+			return None;
+		}
+		let mut xml_body = String::from(r#"<?xml version="1.0" encoding="UTF-8"?><CreateBucketConfiguration>"#);
+		if let Some(storage_class) = &self.storage_class {
+			xml_body.push_str(&format!("<StorageClass>{}</StorageClass>", storage_class.as_str()));
+		}
+		if let Some(data_redundancy_type) = &self.data_redundancy_type {
+			xml_body.push_str(&format!("<DataRedundancyType>{}</DataRedundancyType>", data_redundancy_type.as_str()));
+		}
+		xml_body.push_str("</CreateBucketConfiguration>");
+		Some(xml_body)
+	}
+
+	pub(crate) fn apply(&self, headers: &mut reqwest::header::HeaderMap) -> anyhow::Result<()> {
+		if let Some(acl) = &self.acl {
+			headers.insert("x-oss-acl", acl.as_str().try_into()?);
+		}
+		if let Some(resource_group_id) = &self.resource_group_id {
+			headers.insert("x-oss-resource-group-id", resource_group_id.try_into()?);
+		}
+		Ok(())
+	}
+}
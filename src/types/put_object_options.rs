@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+
+/// # 上传对象时的请求头
+/// 用于 put_object, put_object_stream, append_object 与 copy_object(需配合 `CopyDirective::Replace`)
+/// ```
+/// # use ali_oss::{Acl, PutObjectOptions, StorageClass};
+/// let options = PutObjectOptions::new().content_type("text/html; charset=utf-8").cache_control("no-cache").meta("author", "ali-oss").acl(Acl::Private).storage_class(StorageClass::IA).tag("project", "demo").forbid_overwrite(true);
+/// assert_eq!(options.metadata.get("author").map(|v| v.as_str()), Some("ali-oss"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PutObjectOptions {
+	/// 用户自定义元数据, 对应 `x-oss-meta-{key}`, key 不区分大小写
+	pub metadata: BTreeMap<String, String>,
+	/// 为空时根据扩展名与内容推断, 见 `OssConfig::get_content_type`
+	pub content_type: Option<String>,
+	pub content_disposition: Option<String>,
+	pub content_encoding: Option<String>,
+	pub cache_control: Option<String>,
+	pub expires: Option<chrono::DateTime<chrono::Utc>>,
+	pub acl: Option<crate::Acl>,
+	pub storage_class: Option<crate::StorageClass>,
+	/// 对象标签, 对应 `x-oss-tagging`
+	pub tagging: BTreeMap<String, String>,
+	/// 为 true 时不覆盖同名对象, 已存在时返回 FileAlreadyExists
+	pub forbid_overwrite: Option<bool>,
+	/// 服务端加密方式: AES256, KMS, SM4
+	pub server_side_encryption: Option<String>,
+	/// KMS 加密时使用的数据加密算法, 如 SM4
+	pub server_side_data_encryption: Option<String>,
+	/// KMS 托管的用户主密钥
+	pub server_side_encryption_key_id: Option<String>,
+	/// 上传回调, 仅 put_object 与 put_object_stream 支持; 回调服务器的响应在 `PutObjectOutput::callback_body` 中
+	pub callback: Option<crate::CallbackOptions>,
+}
+
+impl PutObjectOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn meta<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
+		self.metadata.insert(key.to_string().to_lowercase(), value.to_string());
+		self
+	}
+	pub fn content_type<T: ToString>(mut self, content_type: T) -> Self {
+		self.content_type = Some(content_type.to_string());
+		self
+	}
+	pub fn content_disposition<T: ToString>(mut self, content_disposition: T) -> Self {
+		self.content_disposition = Some(content_disposition.to_string());
+		self
+	}
+	pub fn content_encoding<T: ToString>(mut self, content_encoding: T) -> Self {
+		self.content_encoding = Some(content_encoding.to_string());
+		self
+	}
+	pub fn cache_control<T: ToString>(mut self, cache_control: T) -> Self {
+		self.cache_control = Some(cache_control.to_string());
+		self
+	}
+	pub fn expires(mut self, expires: chrono::DateTime<chrono::Utc>) -> Self {
+		self.expires = Some(expires);
+		self
+	}
+	pub fn acl(mut self, acl: crate::Acl) -> Self {
+		self.acl = Some(acl);
+		self
+	}
+	pub fn storage_class(mut self, storage_class: crate::StorageClass) -> Self {
+		self.storage_class = Some(storage_class);
+		self
+	}
+	pub fn tag<K: ToString, V: ToString>(mut self, key: K, value: V) -> Self {
+		self.tagging.insert(key.to_string(), value.to_string());
+		self
+	}
+	pub fn forbid_overwrite(mut self, forbid_overwrite: bool) -> Self {
+		self.forbid_overwrite = Some(forbid_overwrite);
+		self
+	}
+	pub fn server_side_encryption<T: ToString>(mut self, server_side_encryption: T) -> Self {
+		self.server_side_encryption = Some(server_side_encryption.to_string());
+		self
+	}
+	pub fn server_side_data_encryption<T: ToString>(mut self, server_side_data_encryption: T) -> Self {
+		self.server_side_data_encryption = Some(server_side_data_encryption.to_string());
+		self
+	}
+	pub fn server_side_encryption_key_id<T: ToString>(mut self, server_side_encryption_key_id: T) -> Self {
+		self.server_side_encryption_key_id = Some(server_side_encryption_key_id.to_string());
+		self
+	}
+	pub fn callback(mut self, callback: crate::CallbackOptions) -> Self {
+		self.callback = Some(callback);
+		self
+	}
+
+	/// 写入请求头, 会覆盖根据内容推断的 Content-Type
+	pub(crate) fn apply(&self, headers: &mut reqwest::header::HeaderMap) -> anyhow::Result<()> {
+		use reqwest::header::{HeaderName, HeaderValue};
+		for (key, value) in &self.metadata {
+			// 元数据支持 UTF-8, 不能使用只接受可见 ASCII 的 HeaderValue::from_str
+			headers.insert(HeaderName::from_bytes(format!("x-oss-meta-{}", key.to_lowercase()).as_bytes())?, HeaderValue::from_bytes(value.as_bytes())?);
+		}
+		if let Some(content_type) = &self.content_type {
+			headers.insert(reqwest::header::CONTENT_TYPE, content_type.try_into()?);
+		}
+		if let Some(content_disposition) = &self.content_disposition {
+			headers.insert(reqwest::header::CONTENT_DISPOSITION, HeaderValue::from_bytes(content_disposition.as_bytes())?);
+		}
+		if let Some(content_encoding) = &self.content_encoding {
+			headers.insert(reqwest::header::CONTENT_ENCODING, content_encoding.try_into()?);
+		}
+		if let Some(cache_control) = &self.cache_control {
+			headers.insert(reqwest::header::CACHE_CONTROL, cache_control.try_into()?);
+		}
+		if let Some(expires) = &self.expires {
+			headers.insert(reqwest::header::EXPIRES, expires.format("%a, %d %b %Y %T GMT").to_string().try_into()?);
+		}
+		if let Some(acl) = &self.acl {
+			headers.insert("x-oss-object-acl", acl.as_str().try_into()?);
+		}
+		if let Some(storage_class) = &self.storage_class {
+			headers.insert("x-oss-storage-class", storage_class.as_str().try_into()?);
+		}
+		if !self.tagging.is_empty() {
+			let tagging = self
+				.tagging
+				.iter()
+				.map(|(k, v)| format!("{}={}", crate::types::signature::uri_encode(k, true), crate::types::signature::uri_encode(v, true)))
+				.collect::<Vec<String>>()
+				.join("&");
+			headers.insert("x-oss-tagging", tagging.try_into()?);
+		}
+		if let Some(forbid_overwrite) = self.forbid_overwrite {
+			headers.insert("x-oss-forbid-overwrite", forbid_overwrite.to_string().try_into()?);
+		}
+		if let Some(server_side_encryption) = &self.server_side_encryption {
+			headers.insert("x-oss-server-side-encryption", server_side_encryption.try_into()?);
+		}
+		if let Some(server_side_data_encryption) = &self.server_side_data_encryption {
+			headers.insert("x-oss-server-side-data-encryption", server_side_data_encryption.try_into()?);
+		}
+		if let Some(server_side_encryption_key_id) = &self.server_side_encryption_key_id {
+			headers.insert("x-oss-server-side-encryption-key-id", server_side_encryption_key_id.try_into()?);
+		}
+		if let Some(callback) = &self.callback {
+			callback.apply(headers)?;
+		}
+		Ok(())
+	}
+}
+
+/// # 拷贝时元数据或标签的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CopyDirective {
+	/// 复制源对象的元数据或标签
+	#[default]
+	Copy,
+	/// 使用请求中指定的元数据或标签
+	Replace,
+}
+
+impl CopyDirective {
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::Copy => "COPY",
+			Self::Replace => "REPLACE",
+		}
+	}
+}
@@ -0,0 +1,172 @@
+use ring::rand::SecureRandom;
+use std::time::Duration;
+
+/// # 重试策略
+/// 每次重试都会重新签名请求; 请求体为不可重放的流(如 `put_object_stream`)时只会发送一次.
+/// 非幂等的请求(POST, 如 `append_object`, `complete_multipart_upload`)默认只在连接未建立时重试
+pub trait RetryPolicy: std::fmt::Debug + Send + Sync {
+	/// 最大尝试次数, 包含第一次请求
+	fn max_attempts(&self) -> usize;
+	/// 第 attempt 次请求失败后, 下一次请求前的等待时间(attempt 从 1 开始)
+	fn backoff(&self, attempt: usize) -> Duration;
+	/// 判断错误是否可以重试
+	fn is_retryable(&self, error: &anyhow::Error) -> bool;
+	/// 判断请求方法是否可以在请求可能已经送达后重试, 默认只允许幂等的方法
+	/// ```
+	/// # use ali_oss::{NoRetry, RetryPolicy};
+	/// assert!(NoRetry.is_retryable_method(&reqwest::Method::PUT));
+	/// assert!(!NoRetry.is_retryable_method(&reqwest::Method::POST));
+	/// ```
+	fn is_retryable_method(&self, method: &reqwest::Method) -> bool {
+		is_idempotent(method)
+	}
+}
+
+pub(crate) fn is_idempotent(method: &reqwest::Method) -> bool {
+	matches!(*method, reqwest::Method::GET | reqwest::Method::HEAD | reqwest::Method::PUT | reqwest::Method::DELETE | reqwest::Method::OPTIONS)
+}
+
+/// 连接未建立时请求一定没有送达, 任何方法都可以重试
+pub(crate) fn is_connect_error(error: &anyhow::Error) -> bool {
+	error.downcast_ref::<reqwest::Error>().is_some_and(|reqwest_error| reqwest_error.is_connect())
+}
+
+/// # 不重试
+#[derive(Debug, Clone, Default)]
+pub struct NoRetry;
+
+impl RetryPolicy for NoRetry {
+	fn max_attempts(&self) -> usize {
+		1
+	}
+	fn backoff(&self, _attempt: usize) -> Duration {
+		Duration::ZERO
+	}
+	fn is_retryable(&self, _error: &anyhow::Error) -> bool {
+		false
+	}
+}
+
+/// # 指数退避重试
+/// 等待时间在 `[0, min(max_delay, base_delay * 2^(attempt - 1))]` 之间随机(full jitter)
+/// ```
+/// # use ali_oss::{ExponentialBackoff, OssErrorCode, RetryPolicy};
+/// # use std::time::Duration;
+/// let policy = ExponentialBackoff::default().with_max_attempts(5).with_base_delay(Duration::from_millis(100)).with_retryable_code(OssErrorCode::Other("ServiceUnavailable".to_string()));
+/// assert_eq!(policy.max_attempts(), 5);
+/// assert!(policy.backoff(3) <= Duration::from_millis(400));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExponentialBackoff {
+	pub max_attempts: usize,
+	pub base_delay: Duration,
+	pub max_delay: Duration,
+	pub retryable_codes: Vec<crate::OssErrorCode>,
+	pub retryable_statuses: Vec<reqwest::StatusCode>,
+	/// 是否重试非幂等的请求(POST), 默认为 false; 开启后可能重复追加或重复触发回调
+	pub retry_non_idempotent: bool,
+}
+
+impl Default for ExponentialBackoff {
+	fn default() -> Self {
+		Self {
+			max_attempts: 3,
+			base_delay: Duration::from_millis(200),
+			max_delay: Duration::from_secs(20),
+			retryable_codes: vec![crate::OssErrorCode::RequestTimeout, crate::OssErrorCode::InternalError],
+			retryable_statuses: vec![
+				reqwest::StatusCode::TOO_MANY_REQUESTS,
+				reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+				reqwest::StatusCode::BAD_GATEWAY,
+				reqwest::StatusCode::SERVICE_UNAVAILABLE,
+				reqwest::StatusCode::GATEWAY_TIMEOUT,
+			],
+			retry_non_idempotent: false,
+		}
+	}
+}
+
+impl ExponentialBackoff {
+	pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+		self.max_attempts = max_attempts;
+		self
+	}
+	pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+		self.base_delay = base_delay;
+		self
+	}
+	pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+		self.max_delay = max_delay;
+		self
+	}
+	pub fn with_retryable_code(mut self, code: crate::OssErrorCode) -> Self {
+		self.retryable_codes.push(code);
+		self
+	}
+	pub fn with_retryable_status(mut self, status: reqwest::StatusCode) -> Self {
+		self.retryable_statuses.push(status);
+		self
+	}
+	pub fn with_retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+		self.retry_non_idempotent = retry_non_idempotent;
+		self
+	}
+}
+
+impl RetryPolicy for ExponentialBackoff {
+	fn max_attempts(&self) -> usize {
+		self.max_attempts
+	}
+	fn backoff(&self, attempt: usize) -> Duration {
+		let exponent = attempt.saturating_sub(1).min(31) as u32;
+		let ceiling = self.base_delay.saturating_mul(2u32.saturating_pow(exponent)).min(self.max_delay);
+		let mut buf = [0u8; 4];
+		if ring::rand::SystemRandom::new().fill(&mut buf).is_err() {
+			return ceiling;
+		}
+		ceiling.mul_f64(u32::from_le_bytes(buf) as f64 / u32::MAX as f64)
+	}
+	/// ```
+	/// # use ali_oss::{ExponentialBackoff, OssError, OssErrorCode, RetryPolicy};
+	/// # #[tokio::main]
+	/// # async fn main() {
+	/// let policy = ExponentialBackoff::default();
+	/// let oss_error = |status: u16, code: &str| -> anyhow::Error { OssError::new_from_xml_string(reqwest::StatusCode::from_u16(status).unwrap(), format!("<Error><Code>{}</Code></Error>", code)).unwrap().into() };
+	/// assert!(policy.is_retryable(&oss_error(500, "InternalError")));
+	/// assert!(policy.is_retryable(&oss_error(503, "ServiceUnavailable")));
+	/// // 重新签名仍使用同一个本地时钟, 时间偏差无法通过重试解决
+	/// assert!(!policy.is_retryable(&oss_error(403, "RequestTimeTooSkewed")));
+	/// assert!(!policy.is_retryable(&oss_error(403, "AccessDenied")));
+	/// assert!(!policy.is_retryable(&oss_error(404, "NoSuchKey")));
+	/// assert!(!policy.is_retryable(&anyhow::anyhow!("other error")));
+	///
+	/// // 连接被拒绝
+	/// let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+	/// let connect_error = reqwest::get(format!("http://127.0.0.1:{}", port)).await.unwrap_err();
+	/// assert!(policy.is_retryable(&connect_error.into()));
+	/// // 超时
+	/// let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+	/// let http_client = reqwest::Client::builder().timeout(std::time::Duration::from_millis(100)).build().unwrap();
+	/// let timeout_error = http_client.get(format!("http://{}", listener.local_addr().unwrap())).send().await.unwrap_err();
+	/// assert!(policy.is_retryable(&timeout_error.into()));
+	/// // 无效的 URL 等请求构造错误
+	/// let builder_error = reqwest::get("http://[::1").await.unwrap_err();
+	/// assert!(!policy.is_retryable(&builder_error.into()));
+	///
+	/// assert!(!policy.is_retryable_method(&reqwest::Method::POST));
+	/// assert!(policy.with_retry_non_idempotent(true).is_retryable_method(&reqwest::Method::POST));
+	/// # }
+	/// ```
+	fn is_retryable(&self, error: &anyhow::Error) -> bool {
+		if let Some(oss_error) = error.downcast_ref::<crate::OssError>() {
+			return self.retryable_codes.contains(&oss_error.code) || self.retryable_statuses.contains(&oss_error.status);
+		}
+		if let Some(reqwest_error) = error.downcast_ref::<reqwest::Error>() {
+			return reqwest_error.is_timeout() || reqwest_error.is_connect();
+		}
+		false
+	}
+	fn is_retryable_method(&self, method: &reqwest::Method) -> bool {
+		self.retry_non_idempotent || is_idempotent(method)
+	}
+}
@@ -1,7 +1,6 @@
 use crate::types;
 use base64::{engine::general_purpose::STANDARD, Engine};
 use reqwest::Method;
-use std::ops::Deref;
 
 #[derive(Debug)]
 pub struct HeaderSignature {
@@ -69,9 +68,9 @@ impl HeaderSignature {
 }
 
 impl super::SignatureAble for HeaderSignature {
-	fn get_signature_string<T: Deref<Target = crate::OssConfig>>(&self, oss_config: T) -> String {
+	fn get_signature_string(&self, credentials: &crate::Credentials) -> String {
 		let string_to_sign = self.get_string_to_sign();
-		let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, oss_config.access_key_secret.as_bytes());
+		let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, credentials.access_key_secret.as_bytes());
 		let hmac_signature = ring::hmac::sign(&key, string_to_sign.as_bytes());
 		STANDARD.encode(hmac_signature.as_ref())
 	}
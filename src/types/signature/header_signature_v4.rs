@@ -0,0 +1,95 @@
+use reqwest::Method;
+use std::collections::BTreeMap;
+
+/// # V4 请求头签名
+/// https://help.aliyun.com/zh/oss/developer-reference/recommend-to-use-signature-version-4
+/// ```
+/// # use ali_oss::{Credentials, HeaderSignatureV4, SignatureAble};
+/// # use std::collections::BTreeMap;
+/// // OSS SDK 中 V4 请求头签名的示例
+/// let to_map = |pairs: &[(&str, &str)]| pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<BTreeMap<String, String>>();
+/// let query = to_map(&[("param1", "value1"), ("+param1", "value3"), ("|param1", "value4"), ("+param2", ""), ("|param2", ""), ("param2", "")]);
+/// let headers = to_map(&[("content-type", "text/plain"), ("x-oss-content-sha256", "UNSIGNED-PAYLOAD"), ("x-oss-date", "20231216T162057Z"), ("x-oss-head1", "value")]);
+/// let date = "2023-12-16T16:20:57Z".parse().unwrap();
+/// let signature = HeaderSignatureV4::new(reqwest::Method::PUT, "/bucket/1234+-/123/1.txt".to_string(), query, headers, vec![], date, "cn-hangzhou".to_string(), "oss".to_string());
+/// let credentials = Credentials::new("ak", "sk", None);
+/// let authorization = signature.get_authorization("ak", &signature.get_signature_string(&credentials));
+/// assert_eq!(authorization, "OSS4-HMAC-SHA256 Credential=ak/20231216/cn-hangzhou/oss/aliyun_v4_request,Signature=e21d18daa82167720f9b1047ae7e7f1ce7cb77a31e8203a7d5f4624fa0284afe");
+/// ```
+#[derive(Debug)]
+pub struct HeaderSignatureV4 {
+	pub method: Method,
+	/// 未编码的 `/{bucket}/{object}`, 无 bucket 时为 `/`
+	pub canonical_uri: String,
+	pub query: BTreeMap<String, String>,
+	/// 参与签名的请求头(小写): content-type, content-md5, x-oss-* 以及 additional_headers
+	pub headers: BTreeMap<String, String>,
+	pub additional_headers: Vec<String>,
+	pub date: chrono::DateTime<chrono::Utc>,
+	pub region: String,
+	pub product: String,
+}
+
+impl HeaderSignatureV4 {
+	#[allow(clippy::too_many_arguments)]
+	pub fn new(
+		method: Method,
+		canonical_uri: String,
+		query: BTreeMap<String, String>,
+		headers: BTreeMap<String, String>,
+		additional_headers: Vec<String>,
+		date: chrono::DateTime<chrono::Utc>,
+		region: String,
+		product: String,
+	) -> Self {
+		let mut additional_headers: Vec<String> = additional_headers.into_iter().map(|k| k.to_lowercase()).collect();
+		additional_headers.sort();
+		additional_headers.dedup();
+		Self {
+			method,
+			canonical_uri,
+			query,
+			headers,
+			additional_headers,
+			date,
+			region,
+			product,
+		}
+	}
+	/// # ISO8601 格式的时间, 用于 x-oss-date
+	/// ```
+	/// # use ali_oss::HeaderSignatureV4;
+	/// # use std::collections::BTreeMap;
+	/// let date = "2023-12-03T12:12:12Z".parse().unwrap();
+	/// let signature = HeaderSignatureV4::new(reqwest::Method::GET, "/".to_string(), BTreeMap::new(), BTreeMap::new(), vec![], date, "cn-hangzhou".to_string(), "oss".to_string());
+	/// assert_eq!(signature.get_date_string(), "20231203T121212Z");
+	/// assert_eq!(signature.get_scope(), "20231203/cn-hangzhou/oss/aliyun_v4_request");
+	/// ```
+	pub fn get_date_string(&self) -> String {
+		self.date.format("%Y%m%dT%H%M%SZ").to_string()
+	}
+	pub fn get_scope(&self) -> String {
+		format!("{}/{}/{}/{}", self.date.format("%Y%m%d"), self.region, self.product, super::V4_REQUEST)
+	}
+	pub fn get_canonical_request(&self) -> String {
+		super::v4_canonical_request(self.method.as_str(), &self.canonical_uri, &self.query, &self.headers, &self.additional_headers)
+	}
+	pub fn get_string_to_sign(&self) -> String {
+		super::v4_string_to_sign(&self.get_date_string(), &self.get_scope(), &self.get_canonical_request())
+	}
+	pub fn get_authorization(&self, access_key_id: &str, signature: &str) -> String {
+		let mut authorization = format!("{} Credential={}/{}", super::V4_ALGORITHM, access_key_id, self.get_scope());
+		if !self.additional_headers.is_empty() {
+			authorization.push_str(&format!(",AdditionalHeaders={}", self.additional_headers.join(";")));
+		}
+		authorization.push_str(&format!(",Signature={}", signature));
+		authorization
+	}
+}
+
+impl super::SignatureAble for HeaderSignatureV4 {
+	fn get_signature_string(&self, credentials: &crate::Credentials) -> String {
+		let signing_key = super::v4_signing_key(&credentials.access_key_secret, &self.date.format("%Y%m%d").to_string(), &self.region, &self.product);
+		super::hex_encode(&super::hmac_sha256(&signing_key, self.get_string_to_sign().as_bytes()))
+	}
+}
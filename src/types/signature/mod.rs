@@ -1,10 +1,79 @@
 mod header_signature;
+mod header_signature_v4;
 mod param_signature;
+mod param_signature_v4;
 
 pub use header_signature::HeaderSignature;
+pub use header_signature_v4::HeaderSignatureV4;
 pub use param_signature::ParamSignature;
+pub use param_signature_v4::ParamSignatureV4;
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::collections::BTreeMap;
 
-use std::ops::Deref;
 pub trait SignatureAble {
-	fn get_signature_string<T: Deref<Target = crate::OssConfig>>(&self, oss_config: T) -> String;
+	fn get_signature_string(&self, credentials: &crate::Credentials) -> String;
+}
+
+/// # 签名版本
+/// V1 为 HMAC-SHA1, V4 为 OSS4-HMAC-SHA256
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignatureVersion {
+	#[default]
+	V1,
+	V4,
+}
+
+pub(crate) const V4_ALGORITHM: &str = "OSS4-HMAC-SHA256";
+pub(crate) const V4_UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+pub(crate) const V4_REQUEST: &str = "aliyun_v4_request";
+pub(crate) const V4_PRODUCT: &str = "oss";
+
+// RFC 3986 中的非保留字符不编码
+const URI_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'_').remove(b'.').remove(b'~');
+const URI_ENCODE_SET_KEEP_SLASH: &AsciiSet = &URI_ENCODE_SET.remove(b'/');
+
+pub(crate) fn uri_encode(input: &str, encode_slash: bool) -> String {
+	if encode_slash {
+		utf8_percent_encode(input, URI_ENCODE_SET).to_string()
+	} else {
+		utf8_percent_encode(input, URI_ENCODE_SET_KEEP_SLASH).to_string()
+	}
+}
+
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
+	bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub(crate) fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+	let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, key);
+	ring::hmac::sign(&key, data).as_ref().to_vec()
+}
+
+/// V4 签名密钥: aliyun_v4 + SK -> date -> region -> product -> aliyun_v4_request
+pub(crate) fn v4_signing_key(access_key_secret: &str, date: &str, region: &str, product: &str) -> Vec<u8> {
+	let date_key = hmac_sha256(format!("aliyun_v4{}", access_key_secret).as_bytes(), date.as_bytes());
+	let date_region_key = hmac_sha256(&date_key, region.as_bytes());
+	let date_region_service_key = hmac_sha256(&date_region_key, product.as_bytes());
+	hmac_sha256(&date_region_service_key, V4_REQUEST.as_bytes())
+}
+
+/// V4 规范请求: 方法, URI, 查询参数, 请求头, 额外签名的请求头, 请求体哈希
+/// 查询参数按编码后的名称排序, 如 `%2Bparam` 排在 `param` 之前
+pub(crate) fn v4_canonical_request(method: &str, canonical_uri: &str, query: &BTreeMap<String, String>, headers: &BTreeMap<String, String>, additional_headers: &[String]) -> String {
+	let mut encoded_query = query.iter().map(|(k, v)| (uri_encode(k, true), uri_encode(v, true))).collect::<Vec<(String, String)>>();
+	encoded_query.sort();
+	let canonical_query = encoded_query
+		.into_iter()
+		.map(|(k, v)| if v.is_empty() { k } else { format!("{}={}", k, v) })
+		.collect::<Vec<String>>()
+		.join("&");
+	let canonical_headers: String = headers.iter().map(|(k, v)| format!("{}:{}\n", k, v.trim())).collect();
+	let hashed_payload = headers.get("x-oss-content-sha256").map(|v| v.as_str()).unwrap_or(V4_UNSIGNED_PAYLOAD);
+	format!("{}\n{}\n{}\n{}\n{}\n{}", method, uri_encode(canonical_uri, false), canonical_query, canonical_headers, additional_headers.join(";"), hashed_payload)
+}
+
+pub(crate) fn v4_string_to_sign(timestamp: &str, scope: &str, canonical_request: &str) -> String {
+	let hashed_request = ring::digest::digest(&ring::digest::SHA256, canonical_request.as_bytes());
+	format!("{}\n{}\n{}\n{}", V4_ALGORITHM, timestamp, scope, hex_encode(hashed_request.as_ref()))
 }
@@ -0,0 +1,94 @@
+use reqwest::Method;
+use std::collections::BTreeMap;
+
+/// # V4 URL 签名
+/// 签名参数 x-oss-signature-version, x-oss-credential, x-oss-date, x-oss-expires 会一并参与签名
+/// ```
+/// # use ali_oss::{Credentials, ParamSignatureV4, SignatureAble};
+/// # use std::collections::BTreeMap;
+/// let to_map = |pairs: &[(&str, &str)]| pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect::<BTreeMap<String, String>>();
+/// let query = to_map(&[("param1", "value1"), ("+param1", "value3"), ("|param1", "value4"), ("+param2", ""), ("|param2", ""), ("param2", "")]);
+/// let headers = to_map(&[("content-type", "text/plain"), ("x-oss-head1", "value")]);
+/// let date = "2023-12-17T02:54:37Z".parse().unwrap();
+/// let expires = std::time::Duration::from_secs(599);
+/// let signature = ParamSignatureV4::new(reqwest::Method::PUT, "/bucket/1234+-/123/1.txt".to_string(), query, headers, vec![], date, expires, "cn-hangzhou".to_string(), "oss".to_string());
+/// let signed_query = signature.get_signed_query("ak");
+/// assert_eq!(signed_query["x-oss-credential"], "ak/20231217/cn-hangzhou/oss/aliyun_v4_request");
+/// assert_eq!(signed_query["x-oss-expires"], "599");
+/// let credentials = Credentials::new("ak", "sk", None);
+/// assert_eq!(signature.get_signature_string(&credentials), "693dc070801f1095ae9c47906083ef532a6446088c4a10e044fb526d935ce504");
+/// ```
+#[derive(Debug)]
+pub struct ParamSignatureV4 {
+	pub method: Method,
+	/// 未编码的 `/{bucket}/{object}`
+	pub canonical_uri: String,
+	pub query: BTreeMap<String, String>,
+	pub headers: BTreeMap<String, String>,
+	pub additional_headers: Vec<String>,
+	pub date: chrono::DateTime<chrono::Utc>,
+	pub expires: std::time::Duration,
+	pub region: String,
+	pub product: String,
+}
+
+impl ParamSignatureV4 {
+	#[allow(clippy::too_many_arguments)]
+	pub fn new(
+		method: Method,
+		canonical_uri: String,
+		query: BTreeMap<String, String>,
+		headers: BTreeMap<String, String>,
+		additional_headers: Vec<String>,
+		date: chrono::DateTime<chrono::Utc>,
+		expires: std::time::Duration,
+		region: String,
+		product: String,
+	) -> Self {
+		let mut additional_headers: Vec<String> = additional_headers.into_iter().map(|k| k.to_lowercase()).collect();
+		additional_headers.sort();
+		additional_headers.dedup();
+		Self {
+			method,
+			canonical_uri,
+			query,
+			headers,
+			additional_headers,
+			date,
+			expires,
+			region,
+			product,
+		}
+	}
+	pub fn get_date_string(&self) -> String {
+		self.date.format("%Y%m%dT%H%M%SZ").to_string()
+	}
+	pub fn get_scope(&self) -> String {
+		format!("{}/{}/{}/{}", self.date.format("%Y%m%d"), self.region, self.product, super::V4_REQUEST)
+	}
+	/// # 除 x-oss-signature 外需要追加到 URL 上的全部查询参数
+	pub fn get_signed_query(&self, access_key_id: &str) -> BTreeMap<String, String> {
+		let mut query = self.query.clone();
+		query.insert("x-oss-signature-version".to_string(), super::V4_ALGORITHM.to_string());
+		query.insert("x-oss-credential".to_string(), format!("{}/{}", access_key_id, self.get_scope()));
+		query.insert("x-oss-date".to_string(), self.get_date_string());
+		query.insert("x-oss-expires".to_string(), self.expires.as_secs().to_string());
+		if !self.additional_headers.is_empty() {
+			query.insert("x-oss-additional-headers".to_string(), self.additional_headers.join(";"));
+		}
+		query
+	}
+	pub fn get_canonical_request(&self, access_key_id: &str) -> String {
+		super::v4_canonical_request(self.method.as_str(), &self.canonical_uri, &self.get_signed_query(access_key_id), &self.headers, &self.additional_headers)
+	}
+	pub fn get_string_to_sign(&self, access_key_id: &str) -> String {
+		super::v4_string_to_sign(&self.get_date_string(), &self.get_scope(), &self.get_canonical_request(access_key_id))
+	}
+}
+
+impl super::SignatureAble for ParamSignatureV4 {
+	fn get_signature_string(&self, credentials: &crate::Credentials) -> String {
+		let signing_key = super::v4_signing_key(&credentials.access_key_secret, &self.date.format("%Y%m%d").to_string(), &self.region, &self.product);
+		super::hex_encode(&super::hmac_sha256(&signing_key, self.get_string_to_sign(&credentials.access_key_id).as_bytes()))
+	}
+}
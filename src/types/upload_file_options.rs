@@ -0,0 +1,57 @@
+/// # upload_file 参数
+/// ```
+/// # use ali_oss::UploadFileOptions;
+/// let options = UploadFileOptions::new().part_size(16 * 1024 * 1024).parallel(8).checkpoint_file("/tmp/video.mp4.ucp");
+/// assert_eq!(options.parallel, 8);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UploadFileOptions {
+	/// 分片大小, 默认 8MB; 分片数超过 10000 时会自动调大
+	pub part_size: u64,
+	/// 并发上传的分片数, 默认 4
+	pub parallel: usize,
+	/// 是否记录断点, 默认 true
+	pub enable_checkpoint: bool,
+	/// 断点文件路径, 默认为 `{path}.ucp`
+	pub checkpoint_file: Option<std::path::PathBuf>,
+	/// 完成上传时的回调
+	pub callback: Option<crate::CallbackOptions>,
+}
+
+impl Default for UploadFileOptions {
+	fn default() -> Self {
+		Self {
+			part_size: 8 * 1024 * 1024,
+			parallel: 4,
+			enable_checkpoint: true,
+			checkpoint_file: None,
+			callback: None,
+		}
+	}
+}
+
+impl UploadFileOptions {
+	pub fn new() -> Self {
+		Self::default()
+	}
+	pub fn part_size(mut self, part_size: u64) -> Self {
+		self.part_size = part_size;
+		self
+	}
+	pub fn callback(mut self, callback: crate::CallbackOptions) -> Self {
+		self.callback = Some(callback);
+		self
+	}
+	pub fn parallel(mut self, parallel: usize) -> Self {
+		self.parallel = parallel;
+		self
+	}
+	pub fn enable_checkpoint(mut self, enable_checkpoint: bool) -> Self {
+		self.enable_checkpoint = enable_checkpoint;
+		self
+	}
+	pub fn checkpoint_file<T: Into<std::path::PathBuf>>(mut self, checkpoint_file: T) -> Self {
+		self.checkpoint_file = Some(checkpoint_file.into());
+		self
+	}
+}
@@ -0,0 +1,233 @@
+use crate::transfer::{is_fatal_error, with_suffix};
+use crate::Client;
+use futures::StreamExt;
+use std::path::Path;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+const MIN_PART_SIZE: u64 = 100 * 1024;
+const MAX_PART_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+const MAX_PART_COUNT: u64 = 10000;
+
+/// # 分片上传的断点记录
+/// 本地文件的大小或修改时间变化后断点失效, 重新上传
+#[derive(Debug, Clone)]
+struct UploadCheckpoint {
+	bucket: String,
+	key: String,
+	file_size: u64,
+	file_mtime: u64,
+	part_size: u64,
+	upload_id: String,
+	parts: Vec<crate::Part>,
+}
+
+impl UploadCheckpoint {
+	async fn load(checkpoint_file: &Path) -> Option<Self> {
+		let json_data = tokio::fs::read(checkpoint_file).await.ok()?;
+		let value: serde_json::Value = serde_json::from_slice(&json_data).ok()?;
+		let mut parts = Vec::new();
+		for part in value["parts"].as_array()? {
+			let mut uploaded = crate::Part::new(part["part_number"].as_u64()? as u32, part["etag"].as_str()?);
+			uploaded.size = part["size"].as_u64();
+			uploaded.crc64 = part["crc64"].as_str().and_then(|crc64| crc64.parse().ok());
+			parts.push(uploaded);
+		}
+		Some(Self {
+			bucket: value["bucket"].as_str()?.to_string(),
+			key: value["key"].as_str()?.to_string(),
+			file_size: value["file_size"].as_u64()?,
+			file_mtime: value["file_mtime"].as_u64()?,
+			part_size: value["part_size"].as_u64()?,
+			upload_id: value["upload_id"].as_str()?.to_string(),
+			parts,
+		})
+	}
+	/// 先写临时文件再重命名, 避免进程中断时留下不完整的断点文件
+	async fn save(&self, checkpoint_file: &Path) -> anyhow::Result<()> {
+		let parts = self
+			.parts
+			.iter()
+			.map(|part| {
+				serde_json::json!({
+					"part_number": part.part_number,
+					"etag": part.etag,
+					"size": part.size,
+					// u64 的 CRC 超出 JSON 数字的安全范围, 以字符串保存
+					"crc64": part.crc64.map(|crc64| crc64.to_string()),
+				})
+			})
+			.collect::<Vec<_>>();
+		let value = serde_json::json!({
+			"bucket": self.bucket,
+			"key": self.key,
+			"file_size": self.file_size,
+			"file_mtime": self.file_mtime,
+			"part_size": self.part_size,
+			"upload_id": self.upload_id,
+			"parts": parts,
+		});
+		let tmp_file = with_suffix(checkpoint_file, ".tmp");
+		tokio::fs::write(&tmp_file, serde_json::to_vec(&value)?).await?;
+		tokio::fs::rename(&tmp_file, checkpoint_file).await?;
+		Ok(())
+	}
+	fn is_valid(&self, bucket: &str, key: &str, file_size: u64, file_mtime: u64, part_size: u64) -> bool {
+		self.bucket == bucket && self.key == key && self.file_size == file_size && self.file_mtime == file_mtime && self.part_size == part_size
+	}
+}
+
+impl Client {
+	/// # 分片并发上传本地文件
+	/// 开启断点时, 中断后使用相同参数再次调用会跳过已上传的分片; 完成后校验 CRC64 并删除断点文件,
+	/// CRC64 不一致时返回带有 key 与 ETag 的 `Crc64MismatchError`, 已合并的对象不会被删除.
+	/// 遇到不可重试的错误(如 AccessDenied)或未开启断点时会取消分片上传, 其他错误保留断点以便续传
+	pub async fn upload_file<P: AsRef<Path>>(&self, path: P, object_name: &str, options: crate::UploadFileOptions) -> anyhow::Result<crate::CompleteMultipartUploadResult> {
+		let path = path.as_ref();
+		let metadata = tokio::fs::metadata(path).await?;
+		if !metadata.is_file() {
+			anyhow::bail!("{} is not a file", path.display());
+		}
+		let file_size = metadata.len();
+		let file_mtime = metadata.modified()?.duration_since(std::time::UNIX_EPOCH)?.as_millis() as u64;
+		let part_size = options.part_size.max(file_size.div_ceil(MAX_PART_COUNT)).max(MIN_PART_SIZE);
+		if part_size > MAX_PART_SIZE {
+			anyhow::bail!("part size {} exceeds {}", part_size, MAX_PART_SIZE);
+		}
+		let part_count = file_size.div_ceil(part_size).max(1);
+		let bucket = self.oss_config().bucket_name.clone();
+		let key = self.oss_config().get_object_name(object_name).into_owned();
+		let checkpoint_file = match &options.checkpoint_file {
+			Some(checkpoint_file) => checkpoint_file.clone(),
+			None => with_suffix(path, ".ucp"),
+		};
+
+		let mut checkpoint = None;
+		if options.enable_checkpoint {
+			if let Some(saved) = UploadCheckpoint::load(&checkpoint_file).await {
+				if saved.is_valid(&bucket, &key, file_size, file_mtime, part_size) {
+					// 只有分片上传已不存在时才放弃断点, 其他错误(如超时)保留断点以便续传
+					match self.list_parts(object_name, &saved.upload_id, None, Some(1)).await {
+						Ok(_) => checkpoint = Some(saved),
+						Err(e) if is_no_such_upload(&e) => {}
+						Err(e) => return Err(e),
+					}
+				} else {
+					// 断点属于其他对象时, 按断点中的 bucket 与 key 取消原来的分片上传
+					let client = self.bucket(saved.bucket.as_str(), self.oss_config().bucket_location.as_str());
+					if let Err(e) = client.abort_multipart_upload(&saved.key, &saved.upload_id).await {
+						if !is_no_such_upload(&e) {
+							return Err(e.context(format!("failed to abort upload {} of {}/{} in checkpoint {}", saved.upload_id, saved.bucket, saved.key, checkpoint_file.display())));
+						}
+					}
+				}
+			}
+		}
+		let mut checkpoint = match checkpoint {
+			Some(checkpoint) => checkpoint,
+			None => {
+				let upload = self.initiate_multipart_upload(object_name).await?;
+				UploadCheckpoint {
+					bucket,
+					key,
+					file_size,
+					file_mtime,
+					part_size,
+					upload_id: upload.upload_id,
+					parts: Vec::new(),
+				}
+			}
+		};
+		if options.enable_checkpoint {
+			checkpoint.save(&checkpoint_file).await?;
+		}
+
+		let pending_parts = (1..=part_count as u32).filter(|part_number| !checkpoint.parts.iter().any(|part| part.part_number == *part_number)).collect::<Vec<_>>();
+		let upload_id = checkpoint.upload_id.clone();
+		let mut uploads = futures::stream::iter(pending_parts)
+			.map(|part_number| {
+				let offset = (part_number as u64 - 1) * part_size;
+				let size = part_size.min(file_size - offset);
+				self.upload_file_part(path, object_name, &upload_id, part_number, offset, size)
+			})
+			.buffer_unordered(options.parallel.max(1));
+		let mut upload_error = None;
+		while let Some(result) = uploads.next().await {
+			match result {
+				Ok(part) => {
+					checkpoint.parts.push(part);
+					if options.enable_checkpoint {
+						checkpoint.save(&checkpoint_file).await?;
+					}
+				}
+				Err(e) => {
+					upload_error = Some(e);
+					break;
+				}
+			}
+		}
+		drop(uploads);
+		if let Some(e) = upload_error {
+			if is_fatal_error(&e) || !options.enable_checkpoint {
+				let _ = self.abort_multipart_upload(object_name, &upload_id).await;
+				if options.enable_checkpoint {
+					let _ = tokio::fs::remove_file(&checkpoint_file).await;
+				}
+			}
+			return Err(e);
+		}
+
+		checkpoint.parts.sort_by_key(|part| part.part_number);
+		let result = match self.complete_multipart_upload(object_name, &upload_id, Some(&checkpoint.parts), options.callback.as_ref()).await {
+			Ok(result) => result,
+			Err(e) => {
+				if is_fatal_error(&e) || !options.enable_checkpoint {
+					let _ = self.abort_multipart_upload(object_name, &upload_id).await;
+					if options.enable_checkpoint {
+						let _ = tokio::fs::remove_file(&checkpoint_file).await;
+					}
+				}
+				return Err(e);
+			}
+		};
+
+		// 合并后分片上传已经结束, 断点无法再续传
+		let crc64_result = self.verify_upload_crc64(&checkpoint.parts, &result);
+		if options.enable_checkpoint {
+			let _ = tokio::fs::remove_file(&checkpoint_file).await;
+		}
+		// 对象已经写入, 是否删除由调用方决定
+		crc64_result.map_err(|e| e.context(format!("object {} (etag {}) does not match the local file", result.key, result.etag)))?;
+		Ok(result)
+	}
+
+	fn verify_upload_crc64(&self, parts: &[crate::Part], result: &crate::CompleteMultipartUploadResult) -> anyhow::Result<()> {
+		if !self.oss_config().enable_crc64 {
+			return Ok(());
+		}
+		let local_crc64 = parts.iter().try_fold(0u64, |crc64, part| Some(crate::Crc64::combine(crc64, part.crc64?, part.size?)));
+		if let (Some(local), Some(remote)) = (local_crc64, result.crc64) {
+			if local != remote {
+				return Err(crate::Crc64MismatchError { local, remote, request_id: result.request_id.clone() }.into());
+			}
+		}
+		Ok(())
+	}
+
+	async fn upload_file_part(&self, path: &Path, object_name: &str, upload_id: &str, part_number: u32, offset: u64, size: u64) -> anyhow::Result<crate::Part> {
+		let mut file = tokio::fs::File::open(path).await?;
+		file.seek(std::io::SeekFrom::Start(offset)).await?;
+		let mut buf = vec![0u8; size as usize];
+		file.read_exact(&mut buf).await?;
+		let crc64 = crate::Crc64::checksum(&buf);
+
+		// upload_part 已与 OSS 返回的 CRC64 比较
+		let mut part = self.upload_part(object_name, upload_id, part_number, buf).await?;
+		part.size = Some(size);
+		part.crc64 = Some(crc64);
+		Ok(part)
+	}
+}
+
+fn is_no_such_upload(error: &anyhow::Error) -> bool {
+	error.downcast_ref::<crate::OssError>().is_some_and(|oss_error| oss_error.code == crate::OssErrorCode::NoSuchUpload)
+}